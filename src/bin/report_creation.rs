@@ -1,34 +1,214 @@
-use clap::Parser;
-use std::{fs, path::PathBuf};
+use clap::{Parser, Subcommand};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use typst::syntax::Source;
 
 use ReportCreation as reportcreation;
 
+/// Exit code for Typst sources that fail to parse.
+const EXIT_SYNTAX: u8 = 2;
+/// Exit code for files that cannot be read or written.
+const EXIT_IO: u8 = 3;
+
 /// Generate a PDF file from an existing Typst document.
 #[derive(Parser)]
-#[command(author, version, about)]
+#[command(
+    author,
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to the Typst file that should be compiled.
-    #[arg(value_name = "INPUT.typ")]
-    input: PathBuf,
+    #[arg(value_name = "INPUT.typ", required = true)]
+    input: Option<PathBuf>,
 
     /// Output path for the generated PDF. Defaults to replacing the extension with `.pdf`.
     #[arg(short, long, value_name = "FILE")]
     output: Option<PathBuf>,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+#[derive(Subcommand)]
+enum Command {
+    /// Build a report from a JSON config and write its Typst source to the current directory.
+    #[cfg(feature = "json")]
+    Render {
+        /// Path to the JSON report config.
+        #[arg(value_name = "CONFIG.json")]
+        config: PathBuf,
+
+        /// Also compile the report to PDF.
+        #[arg(long)]
+        pdf: bool,
+    },
+}
+
+/// A CLI failure, mapped to a process exit code.
+enum CliError {
+    /// The input has Typst syntax errors, each formatted with its position.
+    Syntax(Vec<String>),
+    /// A file could not be read or written.
+    Io(PathBuf, io::Error),
+    /// Any other failure, such as a Typst compilation error.
+    Other(Box<dyn std::error::Error>),
+}
+
+impl CliError {
+    fn exit_code(&self) -> ExitCode {
+        match self {
+            CliError::Syntax(_) => ExitCode::from(EXIT_SYNTAX),
+            CliError::Io(..) => ExitCode::from(EXIT_IO),
+            CliError::Other(_) => ExitCode::FAILURE,
+        }
+    }
+
+    /// Print one line per problem to stderr, coloring the prefix when stderr
+    /// is a terminal.
+    fn report(&self) {
+        let prefix = if io::stderr().is_terminal() {
+            "\x1b[1;31merror\x1b[0m"
+        } else {
+            "error"
+        };
+
+        match self {
+            CliError::Syntax(errors) => {
+                for error in errors {
+                    eprintln!("{prefix}: {error}");
+                }
+            }
+            CliError::Io(path, err) => eprintln!("{prefix}: {}: {err}", path.display()),
+            CliError::Other(err) => eprintln!("{prefix}: {err}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> From<E> for CliError {
+    fn from(err: E) -> Self {
+        CliError::Other(Box::new(err))
+    }
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    let source = fs::read_to_string(&cli.input)?;
-    let output_path = cli
-        .output
-        .clone()
-        .unwrap_or_else(|| cli.input.with_extension("pdf"));
+    let result = match cli.command {
+        #[cfg(feature = "json")]
+        Some(Command::Render { config, pdf }) => render_config(&config, pdf),
+        #[cfg(not(feature = "json"))]
+        Some(command) => match command {},
+        None => compile_typst(
+            &cli.input
+                .expect("clap requires an input without a subcommand"),
+            cli.output,
+        ),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            err.report();
+            err.exit_code()
+        }
+    }
+}
+
+/// Compile an existing Typst file to PDF.
+fn compile_typst(input: &Path, output: Option<PathBuf>) -> Result<(), CliError> {
+    let source = fs::read_to_string(input).map_err(|err| CliError::Io(input.to_path_buf(), err))?;
+    let output_path = output.unwrap_or_else(|| input.with_extension("pdf"));
+
+    let errors = syntax_messages(input, &source);
+    if !errors.is_empty() {
+        return Err(CliError::Syntax(errors));
+    }
 
-    let pdf_bytes = reportcreation::compile_pdf(&source, &cli.input);
-    fs::write(&output_path, &pdf_bytes)?;
+    let pdf_bytes = reportcreation::ReportWorld::new(source, input)
+        .try_compile_pdf()
+        .map_err(|err| CliError::Other(format!("{}: {err}", input.display()).into()))?;
+    fs::write(&output_path, &pdf_bytes).map_err(|err| CliError::Io(output_path.clone(), err))?;
 
     println!("PDF written to {}", output_path.display());
 
     Ok(())
 }
+
+/// Map a report rendering failure to the matching CLI error, so invalid
+/// markup and unwritable files get their dedicated exit codes.
+#[cfg(feature = "json")]
+fn render_error(config: &Path, err: reportcreation::RenderError) -> CliError {
+    use reportcreation::RenderError;
+
+    match err {
+        RenderError::InvalidMarkup(errors) => CliError::Syntax(
+            errors
+                .iter()
+                .map(|error| format!("{}: {}", config.display(), error.message))
+                .collect(),
+        ),
+        RenderError::Io { path, message } => CliError::Io(path, io::Error::other(message)),
+        err => CliError::Other(Box::new(err)),
+    }
+}
+
+/// Parse the source and format each syntax error as
+/// `path:line:column: message`.
+fn syntax_messages(input: &Path, source: &str) -> Vec<String> {
+    let source = Source::detached(source);
+
+    source
+        .root()
+        .errors()
+        .iter()
+        .map(|error| {
+            let position = source
+                .range(error.span)
+                .and_then(|range| {
+                    Some((
+                        source.byte_to_line(range.start)? + 1,
+                        source.byte_to_column(range.start)? + 1,
+                    ))
+                })
+                .map(|(line, column)| format!(":{line}:{column}"))
+                .unwrap_or_default();
+
+            format!("{}{position}: {}", input.display(), error.message)
+        })
+        .collect()
+}
+
+/// Build a report from a JSON config and write its Typst source, and
+/// optionally its PDF, to the current directory.
+#[cfg(feature = "json")]
+fn render_config(config: &Path, pdf: bool) -> Result<(), CliError> {
+    use reportcreation::{OutputFormat, Report};
+
+    let contents =
+        fs::read_to_string(config).map_err(|err| CliError::Io(config.to_path_buf(), err))?;
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    let report = Report::from_config(&value)?.generate_pdf(pdf);
+    report
+        .try_render()
+        .map_err(|err| render_error(config, err))?;
+
+    println!(
+        "Typst written to {}",
+        report.output_path(OutputFormat::Typst).display()
+    );
+    if pdf {
+        println!(
+            "PDF written to {}",
+            report.output_path(OutputFormat::Pdf).display()
+        );
+    }
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
-use super::Block;
+use super::{Block, BlockKind, Text};
+use crate::render::RenderContext;
 
 #[derive(Debug, Clone)]
 pub struct BulletList {
-    items: Vec<String>,
+    items: Vec<Text>,
 }
 
 impl BulletList {
-    pub fn new<T: Into<String>>(items: impl IntoIterator<Item = T>) -> Self {
+    pub fn new<T: Into<Text>>(items: impl IntoIterator<Item = T>) -> Self {
         Self {
             items: items.into_iter().map(Into::into).collect(),
         }
@@ -14,13 +15,48 @@ impl BulletList {
 }
 
 impl Block for BulletList {
+    fn kind(&self) -> BlockKind {
+        BlockKind::BulletList
+    }
+
     fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
         use std::fmt::Write;
 
+        let settings = context.settings();
         for item in &self.items {
-            writeln!(output, "- {}", item.trim()).expect("writing to string never fails");
+            writeln!(output, "- {}", item.render_markup(settings))
+                .expect("writing to string never fails");
         }
 
         output.push('\n');
     }
+
+    fn langs(&self) -> Vec<&str> {
+        self.items.iter().filter_map(Text::lang_tag).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_plain_items_and_renders_styled_items() {
+        let mut rendered = String::new();
+        let list = BulletList::new([
+            Text::new("Bracketed [note]"),
+            Text::new("Important").weight("bold"),
+        ]);
+
+        list.render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "- Bracketed \\[note\\]\n- #text(\"Important\", weight: \"bold\")\n\n"
+        );
+    }
 }
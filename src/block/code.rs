@@ -1,25 +1,129 @@
-use super::Block;
+use super::{Block, BlockKind, label::validated_label};
+use crate::typst_escape::escape_markup;
+
+use std::fmt::Write;
 
 #[derive(Debug, Clone)]
 pub struct CodeBlock {
     language: Option<String>,
     content: String,
+    caption: Option<String>,
+    label: Option<String>,
 }
 
 impl CodeBlock {
     pub fn new(language: Option<String>, content: String) -> Self {
-        Self { language, content }
+        Self {
+            language,
+            content,
+            caption: None,
+            label: None,
+        }
     }
-}
 
-impl Block for CodeBlock {
-    fn render(&self, output: &mut String) {
-        use std::fmt::Write;
+    /// Caption the code block, rendering it as a `#figure(..., kind: raw)`
+    /// listing.
+    pub fn caption<T: Into<String>>(mut self, caption: T) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
 
+    /// Attach a `<label>` to the listing so it can be referenced elsewhere.
+    ///
+    /// Labelled code blocks are rendered as `#figure(..., kind: raw)` listings.
+    ///
+    /// # Panics
+    /// Panics if the label is not a valid Typst label.
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(validated_label(label.into()));
+        self
+    }
+
+    /// Write the fenced raw block without any trailing blank line.
+    fn write_fence(&self, output: &mut String) {
         let lang = self.language.as_deref().unwrap_or("typst");
         writeln!(output, "```{}", lang).expect("writing to string never fails");
         writeln!(output, "{}", self.content.trim_end()).expect("writing to string never fails");
-        writeln!(output, "```").expect("writing to string never fails");
+        write!(output, "```").expect("writing to string never fails");
+    }
+
+    /// Write the code block wrapped in a raw-kind figure.
+    fn write_listing(&self, output: &mut String) {
+        output.push_str("#figure([");
+        self.write_fence(output);
+        output.push(']');
+
+        if let Some(caption) = &self.caption {
+            write!(output, ", caption: [{}]", escape_markup(caption))
+                .expect("writing to string never fails");
+        }
+
+        output.push_str(", kind: raw)");
+
+        if let Some(label) = &self.label {
+            write!(output, " <{}>", label).expect("writing to string never fails");
+        }
+    }
+}
+
+impl Block for CodeBlock {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Code
+    }
+
+    fn render(&self, output: &mut String) {
+        if self.caption.is_some() || self.label.is_some() {
+            self.write_listing(output);
+        } else {
+            self.write_fence(output);
+        }
+
+        output.push('\n');
         output.push('\n');
     }
+
+    fn labels(&self) -> Vec<&str> {
+        self.label.as_deref().into_iter().collect()
+    }
+}
+
+impl From<CodeBlock> for super::BlockNode {
+    fn from(value: CodeBlock) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_plain_fenced_block_without_caption_or_label() {
+        let mut rendered = String::new();
+
+        CodeBlock::new(Some("rust".to_string()), "fn main() {}".to_string()).render(&mut rendered);
+
+        assert_eq!(rendered, "```rust\nfn main() {}\n```\n\n");
+    }
+
+    #[test]
+    fn renders_labelled_listing_figure() {
+        let mut rendered = String::new();
+
+        CodeBlock::new(Some("rust".to_string()), "fn main() {}".to_string())
+            .caption("Entry point")
+            .label("lst:main")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#figure([```rust\nfn main() {}\n```], caption: [Entry point], kind: raw) <lst:main>\n\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Typst label")]
+    fn rejects_invalid_labels() {
+        let _ = CodeBlock::new(None, "x".to_string()).label("not a label");
+    }
 }
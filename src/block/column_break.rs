@@ -0,0 +1,64 @@
+use super::{Block, BlockKind};
+
+/// Forces the following content into the next column of a multi-column
+/// layout.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnBreak {
+    weak: bool,
+}
+
+impl ColumnBreak {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip the break when it would produce an empty column, such as at the
+    /// top of a column. Defaults to `false`.
+    pub fn weak(mut self, weak: bool) -> Self {
+        self.weak = weak;
+        self
+    }
+}
+
+impl Block for ColumnBreak {
+    fn kind(&self) -> BlockKind {
+        BlockKind::ColumnBreak
+    }
+
+    fn render(&self, output: &mut String) {
+        if self.weak {
+            output.push_str("#colbreak(weak: true)\n\n");
+        } else {
+            output.push_str("#colbreak()\n\n");
+        }
+    }
+}
+
+impl From<ColumnBreak> for super::BlockNode {
+    fn from(value: ColumnBreak) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_column_break() {
+        let mut rendered = String::new();
+
+        ColumnBreak::new().render(&mut rendered);
+
+        assert_eq!(rendered, "#colbreak()\n\n");
+    }
+
+    #[test]
+    fn renders_weak_column_break() {
+        let mut rendered = String::new();
+
+        ColumnBreak::new().weak(true).render(&mut rendered);
+
+        assert_eq!(rendered, "#colbreak(weak: true)\n\n");
+    }
+}
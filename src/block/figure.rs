@@ -1,8 +1,10 @@
-use super::{Block, Image};
+use super::{Block, BlockKind, Image, label::validated_label};
+use crate::typst_escape::escape_markup;
 
 use std::fmt::Write;
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum FigureBody {
     Image(Image),
     Table(super::TableBlock),
@@ -21,6 +23,8 @@ pub struct Figure {
     body: FigureBody,
     caption: Option<String>,
     kind: Option<FigureKind>,
+    label: Option<String>,
+    outlined: bool,
 }
 
 impl Figure {
@@ -29,6 +33,8 @@ impl Figure {
             body: body.into(),
             caption: None,
             kind: None,
+            label: None,
+            outlined: true,
         }
     }
 
@@ -41,15 +47,36 @@ impl Figure {
         self.kind = Some(kind);
         self
     }
+
+    /// Configure whether the figure is listed in the table of figures and
+    /// other figure outlines. Defaults to `true`; turn it off for decorative
+    /// figures such as logos.
+    pub fn outlined(mut self, outlined: bool) -> Self {
+        self.outlined = outlined;
+        self
+    }
+
+    /// Attach a `<label>` to the figure so it can be referenced elsewhere.
+    ///
+    /// # Panics
+    /// Panics if the label is not a valid Typst label.
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(validated_label(label.into()));
+        self
+    }
 }
 
 impl Block for Figure {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Figure
+    }
+
     fn render(&self, output: &mut String) {
         write!(output, "#figure({}", self.body.render_markup())
             .expect("writing to string never fails");
 
         if let Some(caption) = &self.caption {
-            write!(output, ", caption: [{}]", escape_caption(caption))
+            write!(output, ", caption: [{}]", escape_markup(caption))
                 .expect("writing to string never fails");
         }
 
@@ -57,8 +84,28 @@ impl Block for Figure {
             write!(output, ", kind: {}", kind).expect("writing to string never fails");
         }
 
-        writeln!(output, ")").expect("writing to string never fails");
-        output.push('\n');
+        if !self.outlined {
+            output.push_str(", outlined: false");
+        }
+
+        output.push(')');
+
+        if let Some(label) = &self.label {
+            write!(output, " <{}>", label).expect("writing to string never fails");
+        }
+
+        output.push_str("\n\n");
+    }
+
+    fn images(&self) -> Vec<&Image> {
+        match &self.body {
+            FigureBody::Image(image) => vec![image],
+            FigureBody::Table(_) => Vec::new(),
+        }
+    }
+
+    fn labels(&self) -> Vec<&str> {
+        self.label.as_deref().into_iter().collect()
     }
 }
 
@@ -100,13 +147,6 @@ impl std::fmt::Display for FigureKind {
     }
 }
 
-fn escape_caption(caption: &str) -> String {
-    caption
-        .replace('\\', "\\\\")
-        .replace('[', "\\[")
-        .replace(']', "\\]")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,10 +177,41 @@ mod tests {
 
         Figure::new(table).render(&mut rendered);
 
-        assert!(rendered.starts_with("#figure(table(columns: ((flex: 1,), (flex: 1,)))["));
+        assert!(rendered.starts_with("#figure(table(\n  columns: (1fr, 1fr),\n"));
         assert!(rendered.ends_with(")\n\n"));
     }
 
+    #[test]
+    fn renders_label_after_figure() {
+        let mut rendered = String::new();
+
+        Figure::new(Image::new("./plot.png"))
+            .label("fig:plot")
+            .render(&mut rendered);
+
+        assert_eq!(rendered, "#figure(image(\"./plot.png\")) <fig:plot>\n\n");
+    }
+
+    #[test]
+    fn omits_figure_from_outline_only_when_disabled() {
+        let mut hidden = String::new();
+        let mut listed = String::new();
+
+        Figure::new(Image::new("./logo.png"))
+            .caption("Logo")
+            .outlined(false)
+            .render(&mut hidden);
+        Figure::new(Image::new("./logo.png"))
+            .caption("Logo")
+            .render(&mut listed);
+
+        assert_eq!(
+            hidden,
+            "#figure(image(\"./logo.png\"), caption: [Logo], outlined: false)\n\n"
+        );
+        assert!(!listed.contains("outlined"));
+    }
+
     #[test]
     fn escapes_caption_characters() {
         let mut rendered = String::new();
@@ -148,7 +219,18 @@ mod tests {
             .caption("Bracket [and] slash \\")
             .render(&mut rendered);
 
-        let escaped = escape_caption("Bracket [and] slash \\");
+        let escaped = escape_markup("Bracket [and] slash \\");
         assert!(rendered.contains(&format!("caption: [{}]", escaped)));
     }
+
+    #[test]
+    fn escapes_markup_metacharacters_in_captions() {
+        let mut rendered = String::new();
+
+        Figure::new(Image::new("./plot.png"))
+            .caption("Figure #1 *draft* snake_case")
+            .render(&mut rendered);
+
+        assert!(rendered.contains("caption: [Figure \\#1 \\*draft\\* snake\\_case]"));
+    }
 }
@@ -0,0 +1,112 @@
+use super::{Block, BlockKind, Image};
+
+use std::fmt::Write;
+
+/// Default number of grid columns used by a [`Gallery`].
+const DEFAULT_COLUMNS: usize = 2;
+
+#[derive(Debug, Clone)]
+pub struct Gallery {
+    images: Vec<Image>,
+    columns: usize,
+    gutter: Option<String>,
+}
+
+impl Gallery {
+    pub fn new<I: Into<Image>>(images: impl IntoIterator<Item = I>) -> Self {
+        Self {
+            images: images.into_iter().map(Into::into).collect(),
+            columns: DEFAULT_COLUMNS,
+            gutter: None,
+        }
+    }
+
+    /// Set the number of grid columns. Defaults to `2`.
+    ///
+    /// # Panics
+    /// Panics if `columns` is zero.
+    pub fn columns(mut self, columns: usize) -> Self {
+        assert!(columns > 0, "gallery needs at least one column");
+        self.columns = columns;
+        self
+    }
+
+    /// Set the spacing between grid cells, such as `"8pt"`.
+    pub fn gutter<T: Into<String>>(mut self, gutter: T) -> Self {
+        self.gutter = Some(gutter.into());
+        self
+    }
+}
+
+impl Block for Gallery {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Gallery
+    }
+
+    fn render(&self, output: &mut String) {
+        write!(output, "#grid(columns: {}", self.columns).expect("writing to string never fails");
+
+        if let Some(gutter) = &self.gutter {
+            write!(output, ", gutter: {}", gutter).expect("writing to string never fails");
+        }
+
+        for image in &self.images {
+            write!(output, ", {}", image.render_markup(false))
+                .expect("writing to string never fails");
+        }
+
+        writeln!(output, ")").expect("writing to string never fails");
+        output.push('\n');
+    }
+
+    fn images(&self) -> Vec<&Image> {
+        self.images.iter().collect()
+    }
+}
+
+impl From<Gallery> for super::BlockNode {
+    fn from(value: Gallery) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_images_in_a_two_column_grid_by_default() {
+        let mut rendered = String::new();
+
+        Gallery::new([
+            Image::new("./one.png"),
+            Image::new("./two.png").width("100%"),
+            Image::new("./three.png"),
+        ])
+        .gutter("6pt")
+        .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#grid(columns: 2, gutter: 6pt, image(\"./one.png\"), image(\"./two.png\", width: 100%), image(\"./three.png\"))\n\n"
+        );
+        assert_eq!(rendered.matches("image(").count(), 3);
+    }
+
+    #[test]
+    fn honors_custom_column_count() {
+        let mut rendered = String::new();
+
+        Gallery::new(["./a.png", "./b.png", "./c.png"])
+            .columns(3)
+            .render(&mut rendered);
+
+        assert!(rendered.starts_with("#grid(columns: 3, image("));
+    }
+
+    #[test]
+    #[should_panic(expected = "gallery needs at least one column")]
+    fn rejects_zero_columns() {
+        let _ = Gallery::new(["./a.png"]).columns(0);
+    }
+}
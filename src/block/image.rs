@@ -1,4 +1,12 @@
-use super::Block;
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+};
+
+use typst::visualize::RasterFormat;
+
+use super::{Block, BlockKind, length};
+use crate::typst_escape::{escape_markup, escape_string};
 
 #[derive(Debug, Clone, Default)]
 pub struct ImageOptions {
@@ -24,6 +32,12 @@ pub enum ImageOptionValue {
 pub struct Image {
     path: String,
     options: ImageOptions,
+    scale: Option<String>,
+    rotate: Option<String>,
+    border: Option<String>,
+    bytes: Option<Arc<[u8]>>,
+    caption: Option<String>,
+    caption_counter: Option<String>,
 }
 
 impl Image {
@@ -31,6 +45,58 @@ impl Image {
         Self {
             path: path.into(),
             options: ImageOptions::default(),
+            scale: None,
+            rotate: None,
+            border: None,
+            bytes: None,
+            caption: None,
+            caption_counter: None,
+        }
+    }
+
+    /// Create an image from encoded bytes, such as a PNG produced in memory.
+    ///
+    /// The `#image(...)` call points at a name derived from the bytes, which
+    /// the report serves to the compiler from memory. When the report keeps
+    /// its Typst source on disk, the bytes are also written next to it under
+    /// that name so the source compiles on its own. The format is detected
+    /// from the bytes; set [`Image::format`] for SVG data.
+    pub fn from_bytes<B: Into<Vec<u8>>>(bytes: B) -> Self {
+        let bytes: Arc<[u8]> = bytes.into().into();
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+
+        let mut image = Self::new(format!("report-image-{:016x}", hasher.finish()));
+        image.bytes = Some(bytes);
+        image
+    }
+
+    /// Return the path the image is loaded from.
+    pub fn path(&self) -> &str {
+        self.path.trim()
+    }
+
+    /// Return the in-memory bytes of an image created with
+    /// [`Image::from_bytes`].
+    pub(crate) fn bytes(&self) -> Option<&[u8]> {
+        self.bytes.as_deref()
+    }
+
+    /// Return the file format of the in-memory bytes, such as `"png"`: the
+    /// [`Image::format`] if set, otherwise the format detected from the bytes,
+    /// or `"image"` when neither is known.
+    pub(crate) fn bytes_format(&self) -> &str {
+        if let Some(ImageOptionValue::Str(format) | ImageOptionValue::Raw(format)) =
+            &self.options.format
+        {
+            return format;
+        }
+
+        match self.bytes().and_then(RasterFormat::detect) {
+            Some(RasterFormat::Png) => "png",
+            Some(RasterFormat::Jpg) => "jpg",
+            Some(RasterFormat::Gif) => "gif",
+            None => "image",
         }
     }
 
@@ -39,8 +105,12 @@ impl Image {
         self
     }
 
+    /// Describe the image for screen readers.
+    ///
+    /// Surrounding whitespace is trimmed, and an empty description omits the
+    /// `alt` argument entirely, marking the image as decorative.
     pub fn alt<T: Into<String>>(mut self, alt: T) -> Self {
-        self.options.alt = Some(ImageOptionValue::str(alt));
+        self.options = self.options.alt(alt);
         self
     }
 
@@ -54,6 +124,56 @@ impl Image {
         self
     }
 
+    /// Set the width in points.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn width_pt(self, width: f32) -> Self {
+        self.width(length(width, "pt"))
+    }
+
+    /// Set the width in centimeters.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn width_cm(self, width: f32) -> Self {
+        self.width(length(width, "cm"))
+    }
+
+    /// Set the width as a percentage of the available space, such as `80.0`
+    /// for `80%`.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn width_percent(self, width: f32) -> Self {
+        self.width(length(width, "%"))
+    }
+
+    /// Set the height in points.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn height_pt(self, height: f32) -> Self {
+        self.height(length(height, "pt"))
+    }
+
+    /// Set the height in centimeters.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn height_cm(self, height: f32) -> Self {
+        self.height(length(height, "cm"))
+    }
+
+    /// Set the height as a percentage of the available space, such as `80.0`
+    /// for `80%`.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn height_percent(self, height: f32) -> Self {
+        self.height(length(height, "%"))
+    }
+
     pub fn fit<T: Into<String>>(mut self, fit: T) -> Self {
         self.options.fit = Some(ImageOptionValue::raw(fit));
         self
@@ -84,6 +204,49 @@ impl Image {
         self
     }
 
+    /// Wrap the image in a `#scale(...)` call, such as `scale("80%")`.
+    pub fn scale<T: Into<String>>(mut self, factor: T) -> Self {
+        self.scale = Some(factor.into());
+        self
+    }
+
+    /// Wrap the image in a `#rotate(...)` call, such as `rotate("90deg")`.
+    ///
+    /// Rotation is applied outside of any scaling.
+    pub fn rotate<T: Into<String>>(mut self, angle: T) -> Self {
+        self.rotate = Some(angle.into());
+        self
+    }
+
+    /// Draw a border around the image by wrapping it in a
+    /// `#box(stroke: ...)`, such as `border("1pt + gray")`.
+    ///
+    /// The border is applied outside of any scaling and rotation.
+    pub fn border<T: Into<String>>(mut self, stroke: T) -> Self {
+        self.border = Some(stroke.into());
+        self
+    }
+
+    /// Show a centered caption below the image without turning it into a
+    /// figure.
+    ///
+    /// Captions only apply when the image is added as a block on its own;
+    /// figures and galleries have captions of their own.
+    pub fn caption<T: Into<String>>(mut self, caption: T) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Number the caption with a named counter, such as `"Screenshot"`, so
+    /// captions read "Screenshot 1", "Screenshot 2", and so on.
+    ///
+    /// Every image sharing a name steps the same Typst counter, which is
+    /// independent of figure numbering.
+    pub fn caption_counter<T: Into<String>>(mut self, name: T) -> Self {
+        self.caption_counter = Some(name.into());
+        self
+    }
+
     pub(crate) fn render_markup(&self, include_hash: bool) -> String {
         let mut output = String::new();
         self.write_markup(&mut output, include_hash);
@@ -91,29 +254,102 @@ impl Image {
     }
 
     fn write_markup(&self, output: &mut String, include_hash: bool) {
-        use std::fmt::Write;
+        let mut markup = self.image_call();
+        for wrapper in self.wrappers() {
+            markup = format!("{}[#{}]", wrapper, markup);
+        }
 
         if include_hash {
             output.push('#');
         }
 
-        write!(output, "image(\"{}\"", escape_str(self.path.trim()))
+        output.push_str(&markup);
+    }
+
+    /// Build the bare `image(...)` call including all configured options.
+    fn image_call(&self) -> String {
+        use std::fmt::Write;
+
+        let mut call = String::new();
+        write!(call, "image(\"{}\"", escape_string(self.path.trim()))
             .expect("writing to string never fails");
 
         for option in self.options.iter() {
-            write!(output, ", {}", option).expect("writing to string never fails");
+            write!(call, ", {}", option).expect("writing to string never fails");
+        }
+
+        call.push(')');
+        call
+    }
+
+    /// Write the caption line shown below a standalone image, stepping its
+    /// counter first when one is set.
+    fn write_caption(&self, output: &mut String) {
+        use std::fmt::Write;
+
+        if self.caption.is_none() && self.caption_counter.is_none() {
+            return;
+        }
+
+        output.push_str("#align(center)[");
+        if let Some(name) = &self.caption_counter {
+            let name = name.trim();
+            // `;` ends the step call, so a name starting with `(` or `.` is
+            // not read as its arguments or a field access.
+            write!(
+                output,
+                "#counter(\"{}\").step();{} #context counter(\"{}\").display()",
+                escape_string(name),
+                escape_markup(name),
+                escape_string(name)
+            )
+            .expect("writing to string never fails");
+
+            if self.caption.is_some() {
+                output.push_str(": ");
+            }
         }
 
-        output.push(')');
+        if let Some(caption) = &self.caption {
+            output.push_str(&escape_markup(caption.trim()));
+        }
+
+        output.push_str("]\n");
+    }
+
+    /// List the wrapper calls to apply around the image, innermost first.
+    fn wrappers(&self) -> impl Iterator<Item = String> + '_ {
+        [
+            self.scale
+                .as_ref()
+                .map(|factor| format!("scale({})", factor)),
+            self.rotate
+                .as_ref()
+                .map(|angle| format!("rotate({})", angle)),
+            self.border
+                .as_ref()
+                .map(|stroke| format!("box(stroke: {})", stroke)),
+        ]
+        .into_iter()
+        .flatten()
     }
 }
 
 impl Block for Image {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Image
+    }
+
     fn render(&self, output: &mut String) {
         self.write_markup(output, true);
         output.push('\n');
+        self.write_caption(output);
         output.push('\n');
     }
+
+    fn images(&self) -> Vec<&Image> {
+        vec![self]
+    }
 }
 
 impl From<Image> for super::BlockNode {
@@ -135,8 +371,14 @@ impl From<String> for Image {
 }
 
 impl ImageOptions {
+    /// Describe the image for screen readers.
+    ///
+    /// Surrounding whitespace is trimmed, and an empty description omits the
+    /// `alt` argument entirely, marking the image as decorative.
     pub fn alt<T: Into<String>>(mut self, alt: T) -> Self {
-        self.alt = Some(ImageOptionValue::str(alt));
+        let alt = alt.into();
+        let alt = alt.trim();
+        self.alt = (!alt.is_empty()).then(|| ImageOptionValue::str(alt));
         self
     }
 
@@ -211,16 +453,12 @@ impl std::fmt::Display for ImageOptionValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ImageOptionValue::Raw(value) => write!(f, "{}", value),
-            ImageOptionValue::Str(value) => write!(f, "\"{}\"", escape_str(value)),
+            ImageOptionValue::Str(value) => write!(f, "\"{}\"", escape_string(value)),
             ImageOptionValue::Bool(value) => write!(f, "{}", value),
         }
     }
 }
 
-fn escape_str(value: &str) -> String {
-    value.replace('\\', "\\\\").replace('"', "\\\"")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +471,43 @@ mod tests {
         assert_eq!(rendered, "#image(\"./plot.png\")\n\n");
     }
 
+    #[test]
+    fn typed_lengths_add_unit_suffix() {
+        assert_eq!(
+            Image::new("./plot.png")
+                .width_percent(80.0)
+                .render_markup(true),
+            "#image(\"./plot.png\", width: 80%)"
+        );
+        assert_eq!(
+            Image::new("./plot.png")
+                .width_cm(5.0)
+                .height_pt(120.5)
+                .render_markup(true),
+            "#image(\"./plot.png\", width: 5cm, height: 120.5pt)"
+        );
+    }
+
+    #[test]
+    fn numbers_captions_with_named_counter() {
+        let mut rendered = String::new();
+        Image::new("./login.png")
+            .caption("Login page")
+            .caption_counter("Screenshot")
+            .render(&mut rendered);
+        Image::new("./home.png")
+            .caption("Home [beta]")
+            .caption_counter("Screenshot")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#image(\"./login.png\")\n#align(center)[#counter(\"Screenshot\").step();Screenshot #context counter(\"Screenshot\").display(): Login page]\n\n\
+             #image(\"./home.png\")\n#align(center)[#counter(\"Screenshot\").step();Screenshot #context counter(\"Screenshot\").display(): Home \\[beta\\]]\n\n"
+        );
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+
     #[test]
     fn renders_image_with_options() {
         let mut rendered = String::new();
@@ -257,6 +532,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn omits_empty_or_blank_alt_text() {
+        let mut empty = String::new();
+        let mut blank = String::new();
+        let mut described = String::new();
+
+        Image::new("./divider.png").alt("").render(&mut empty);
+        Image::new("./divider.png").alt("   ").render(&mut blank);
+        Image::new("./chart.png")
+            .alt("  Revenue by quarter ")
+            .render(&mut described);
+
+        assert_eq!(empty, "#image(\"./divider.png\")\n\n");
+        assert_eq!(blank, empty);
+        assert_eq!(
+            described,
+            "#image(\"./chart.png\", alt: \"Revenue by quarter\")\n\n"
+        );
+    }
+
+    #[test]
+    fn byte_images_point_at_a_stable_generated_path() {
+        let first = Image::from_bytes(vec![1, 2, 3]);
+        let same = Image::from_bytes(vec![1, 2, 3]);
+        let other = Image::from_bytes(vec![4, 5, 6]);
+
+        assert!(first.path().starts_with("report-image-"));
+        assert_eq!(first.path(), same.path());
+        assert_ne!(first.path(), other.path());
+        assert_eq!(first.bytes(), Some(&[1, 2, 3][..]));
+    }
+
     #[test]
     fn escapes_quotes_and_backslashes() {
         let mut rendered = String::new();
@@ -264,4 +571,56 @@ mod tests {
 
         assert_eq!(rendered, "#image(\".\\\\\\\"plot\\\".png\")\n\n");
     }
+
+    #[test]
+    fn wraps_bordered_image_in_stroked_box() {
+        let mut rendered = String::new();
+
+        Image::new("./screenshot.png")
+            .border("1pt + gray")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#box(stroke: 1pt + gray)[#image(\"./screenshot.png\")]\n\n"
+        );
+    }
+
+    #[test]
+    fn applies_border_outside_scale_and_rotate() {
+        let mut rendered = String::new();
+
+        Image::new("./scan.png")
+            .border("1pt + gray")
+            .rotate("90deg")
+            .scale("80%")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#box(stroke: 1pt + gray)[#rotate(90deg)[#scale(80%)[#image(\"./scan.png\")]]]\n\n"
+        );
+    }
+
+    #[test]
+    fn wraps_image_in_scale_and_rotate_in_stable_order() {
+        let mut rendered = String::new();
+
+        Image::new("./scan.png")
+            .rotate("90deg")
+            .scale("80%")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#rotate(90deg)[#scale(80%)[#image(\"./scan.png\")]]\n\n"
+        );
+    }
+
+    #[test]
+    fn omits_wrappers_when_unset() {
+        let rendered = Image::new("./scan.png").width("50%").render_markup(false);
+
+        assert_eq!(rendered, "image(\"./scan.png\", width: 50%)");
+    }
 }
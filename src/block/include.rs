@@ -0,0 +1,45 @@
+use super::{Block, BlockKind};
+use crate::typst_escape::escape_string;
+
+/// Pulls in the content of another Typst file with `#include "path"`, such as
+/// a chapter kept in a file of its own.
+///
+/// Relative paths resolve against the directory of the generated `.typ` file.
+#[derive(Debug, Clone)]
+pub struct IncludeBlock {
+    path: String,
+}
+
+impl IncludeBlock {
+    pub fn new<P: Into<String>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Block for IncludeBlock {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Include
+    }
+
+    fn render(&self, output: &mut String) {
+        use std::fmt::Write;
+
+        writeln!(output, "#include \"{}\"", escape_string(&self.path))
+            .expect("writing to string never fails");
+        output.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_include_with_escaped_path() {
+        let mut rendered = String::new();
+
+        IncludeBlock::new("parts/\"q3\".typ").render(&mut rendered);
+
+        assert_eq!(rendered, "#include \"parts/\\\"q3\\\".typ\"\n\n");
+    }
+}
@@ -0,0 +1,28 @@
+/// The kind of a [`Block`](super::Block), for passes over a report's blocks
+/// that treat some kinds differently, such as linting or statistics.
+///
+/// New block types add variants, so matches outside this crate need a
+/// wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlockKind {
+    Paragraph,
+    BulletList,
+    NumberedList,
+    TermsList,
+    Table,
+    Code,
+    Raw,
+    Include,
+    Image,
+    Gallery,
+    Figure,
+    Link,
+    Quote,
+    Spacing,
+    ColumnBreak,
+    Metadata,
+    QrCode,
+    /// A block defined outside this crate, named by its type.
+    Other(&'static str),
+}
@@ -0,0 +1,45 @@
+use typst::syntax::is_id_continue;
+
+/// Return whether the value can be used as a Typst `<label>`.
+///
+/// Mirrors the Typst lexer: labels are non-empty and consist of identifier
+/// characters, `:`, or `.`.
+///
+/// # Arguments
+/// - `label`: Candidate label without the surrounding angle brackets.
+pub(crate) fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|ch| is_id_continue(ch) || matches!(ch, ':' | '.'))
+}
+
+/// Return the label unchanged after checking that Typst accepts it.
+///
+/// # Arguments
+/// - `label`: Candidate label without the surrounding angle brackets.
+///
+/// # Panics
+/// Panics if the label contains characters Typst does not allow in labels.
+pub(crate) fn validated_label(label: String) -> String {
+    assert!(is_valid_label(&label), "invalid Typst label: {:?}", label);
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_identifier_like_labels() {
+        assert!(is_valid_label("fig:overview"));
+        assert!(is_valid_label("listing-2.main"));
+    }
+
+    #[test]
+    fn rejects_empty_or_spaced_labels() {
+        assert!(!is_valid_label(""));
+        assert!(!is_valid_label("two words"));
+        assert!(!is_valid_label("bad>label"));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::typst_escape::escape_string;
+
+/// A language tag split into the parts Typst's `text` function accepts.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct LanguageTag {
+    pub(crate) language: String,
+    pub(crate) script: Option<String>,
+    pub(crate) region: Option<String>,
+}
+
+/// Parse a BCP 47-style language tag such as `"en"`, `"en-US"` or
+/// `"zh-Hant-TW"`.
+///
+/// The check is deliberately light: the primary subtag must be two or three
+/// ASCII letters and every further subtag one to eight ASCII alphanumerics.
+/// The first four-letter subtag ahead of the region is taken as the script and
+/// the first two-letter subtag as the region. Numeric UN M.49 regions such as
+/// the `419` in `"es-419"` are accepted but dropped, since Typst only takes
+/// two-letter region codes.
+///
+/// # Arguments
+/// - `tag`: Candidate language tag.
+pub(crate) fn parse_lang(tag: &str) -> Option<LanguageTag> {
+    let mut subtags = tag.split(['-', '_']);
+    let language = subtags.next()?;
+
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|ch| ch.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut script = None;
+    let mut region = None;
+    for subtag in subtags {
+        if !(1..=8).contains(&subtag.len()) || !subtag.chars().all(|ch| ch.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+
+        let is_region = subtag.len() == 2 && subtag.chars().all(|ch| ch.is_ascii_alphabetic());
+        let is_script = subtag.len() == 4 && subtag.chars().all(|ch| ch.is_ascii_alphabetic());
+        if is_script && script.is_none() && region.is_none() {
+            script = Some(subtag.to_ascii_lowercase());
+        }
+        if is_region && region.is_none() {
+            region = Some(subtag.to_ascii_uppercase());
+        }
+    }
+
+    Some(LanguageTag {
+        language: language.to_ascii_lowercase(),
+        script,
+        region,
+    })
+}
+
+/// Render a language tag as Typst `text` arguments, such as
+/// `lang: "zh", script: "hant", region: "TW"`.
+///
+/// A tag that does not parse is passed through as `lang` unchanged;
+/// [`Report::render_validated`](crate::Report::render_validated) rejects such
+/// tags before they reach the compiler.
+///
+/// # Arguments
+/// - `tag`: Language tag to render.
+pub(crate) fn lang_arguments(tag: &str) -> Vec<String> {
+    match parse_lang(tag) {
+        Some(tag) => tag.arguments(),
+        None => vec![format!("lang: \"{}\"", escape_string(tag))],
+    }
+}
+
+impl LanguageTag {
+    /// Render the tag as quoted `lang`, `script` and `region` arguments.
+    pub(crate) fn arguments(&self) -> Vec<String> {
+        let mut arguments = vec![format!("lang: \"{}\"", escape_string(&self.language))];
+        if let Some(script) = &self.script {
+            arguments.push(format!("script: \"{}\"", escape_string(script)));
+        }
+        if let Some(region) = &self.region {
+            arguments.push(format!("region: \"{}\"", escape_string(region)));
+        }
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_primary_language_codes() {
+        let tag = parse_lang("en").expect("two-letter code should parse");
+
+        assert_eq!(tag.language, "en");
+        assert_eq!(tag.script, None);
+        assert_eq!(tag.region, None);
+    }
+
+    #[test]
+    fn splits_region_from_language() {
+        let tag = parse_lang("en-US").expect("tag with region should parse");
+
+        assert_eq!(tag.language, "en");
+        assert_eq!(tag.region.as_deref(), Some("US"));
+        assert_eq!(
+            parse_lang("zh-Hant-TW").and_then(|tag| tag.region),
+            Some("TW".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_script_subtag() {
+        let tag = parse_lang("zh-Hant-TW").expect("tag with script should parse");
+
+        assert_eq!(tag.script.as_deref(), Some("hant"));
+        assert_eq!(
+            lang_arguments("zh-Hant-TW"),
+            ["lang: \"zh\"", "script: \"hant\"", "region: \"TW\""]
+        );
+    }
+
+    #[test]
+    fn drops_numeric_region() {
+        let tag = parse_lang("es-419").expect("tag with numeric region should parse");
+
+        assert_eq!(tag.language, "es");
+        assert_eq!(tag.region, None);
+    }
+
+    #[test]
+    fn rejects_implausible_codes() {
+        assert_eq!(parse_lang("english"), None);
+        assert_eq!(parse_lang("e"), None);
+        assert_eq!(parse_lang("en US"), None);
+    }
+}
@@ -0,0 +1,12 @@
+/// Format a typed length such as `0.1em` or `80%`.
+///
+/// # Arguments
+/// - `value`: Magnitude of the length.
+/// - `unit`: Typst unit suffix, such as `pt`, `cm`, `em`, or `%`.
+///
+/// # Panics
+/// Panics if the value is not finite, since Typst has no literal for it.
+pub(crate) fn length(value: f32, unit: &str) -> String {
+    assert!(value.is_finite(), "length must be finite, got {value}");
+    format!("{value}{unit}")
+}
@@ -1,4 +1,6 @@
-use super::{Block, Text, text::escape_str};
+use super::{Block, BlockKind, Text};
+use crate::render::RenderContext;
+use crate::typst_escape::escape_string;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LinkDestination {
@@ -29,18 +31,36 @@ impl Link {
 }
 
 impl Block for Link {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Link
+    }
+
     fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
         use std::fmt::Write;
 
+        let settings = context.settings();
         let destination = match &self.destination {
-            LinkDestination::Url(url) => format!("target: \"{}\"", escape_str(url)),
-            LinkDestination::Location(location) => format!("location: {}", location),
+            LinkDestination::Url(url) => format!("\"{}\"", escape_string(url)),
+            LinkDestination::Location(location) => location.clone(),
         };
 
-        writeln!(output, "#link({})[{}]", destination, self.content.render())
-            .expect("writing to string never fails");
+        writeln!(
+            output,
+            "#link({})[{}]",
+            destination,
+            self.content.render_with(settings)
+        )
+        .expect("writing to string never fails");
         output.push('\n');
     }
+
+    fn langs(&self) -> Vec<&str> {
+        self.content.lang_tag().into_iter().collect()
+    }
 }
 
 #[cfg(test)]
@@ -53,18 +73,15 @@ mod tests {
 
         Link::to_url("https://example.com", Text::new("Example")).render(&mut output);
 
-        assert_eq!(
-            output,
-            "#link(target: \"https://example.com\")[Example]\n\n"
-        );
+        assert_eq!(output, "#link(\"https://example.com\")[Example]\n\n");
     }
 
     #[test]
     fn renders_location_links() {
         let mut output = String::new();
 
-        Link::to_location("@introduction", Text::new("Jump to Intro")).render(&mut output);
+        Link::to_location("<introduction>", Text::new("Jump to Intro")).render(&mut output);
 
-        assert_eq!(output, "#link(location: @introduction)[Jump to Intro]\n\n");
+        assert_eq!(output, "#link(<introduction>)[Jump to Intro]\n\n");
     }
 }
@@ -0,0 +1,76 @@
+use super::{Block, BlockKind};
+use crate::typst_escape::escape_markup;
+
+use std::fmt::Write;
+
+/// Key/value pairs, such as the author, date, and version of a report, laid
+/// out as a two-column grid with bold keys.
+#[derive(Debug, Clone)]
+pub struct MetadataBlock {
+    entries: Vec<(String, String)>,
+}
+
+impl MetadataBlock {
+    pub fn new<K: Into<String>, V: Into<String>>(
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+impl Block for MetadataBlock {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Metadata
+    }
+
+    fn render(&self, output: &mut String) {
+        output.push_str("#grid(columns: 2, column-gutter: 1em, row-gutter: 0.65em");
+
+        for (key, value) in &self.entries {
+            write!(
+                output,
+                ", [*{}*], [{}]",
+                escape_markup(key),
+                escape_markup(value)
+            )
+            .expect("writing to string never fails");
+        }
+
+        writeln!(output, ")").expect("writing to string never fails");
+        output.push('\n');
+    }
+}
+
+impl From<MetadataBlock> for super::BlockNode {
+    fn from(value: MetadataBlock) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_bold_keys_beside_escaped_values() {
+        let mut rendered = String::new();
+        let metadata = MetadataBlock::new([
+            ("Author:", "Ada *Lovelace*"),
+            ("Date:", "2024-03-15"),
+            ("Version:", "v1.2 #draft"),
+        ]);
+
+        metadata.render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#grid(columns: 2, column-gutter: 1em, row-gutter: 0.65em, [*Author:*], [Ada \\*Lovelace\\*], [*Date:*], [2024-03-15], [*Version:*], [v1.2 \\#draft])\n\n"
+        );
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+}
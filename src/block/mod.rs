@@ -1,25 +1,57 @@
 mod bullet_list;
 mod code;
+mod column_break;
 mod figure;
+mod gallery;
 mod image;
+mod include;
+mod kind;
+mod label;
+mod lang;
+mod length;
 mod link;
+mod metadata;
 mod numbered_list;
 mod paragraph;
+#[cfg(feature = "qr")]
+mod qr_code;
+mod quote;
 mod raw;
+mod rich_text;
+mod spacing;
 mod table;
+mod terms_list;
 mod text;
 
 pub use bullet_list::BulletList;
 pub use code::CodeBlock;
+pub use column_break::ColumnBreak;
 pub use figure::{Figure, FigureBody, FigureKind};
+pub use gallery::Gallery;
 pub use image::{Image, ImageOptions};
+pub use include::IncludeBlock;
+pub use kind::BlockKind;
 pub use link::{Link, LinkDestination};
+pub use metadata::MetadataBlock;
 pub use numbered_list::NumberedList;
 pub use paragraph::Paragraph;
+#[cfg(feature = "qr")]
+pub use qr_code::QrCode;
+pub use quote::{Attribution, Quote};
 pub use raw::RawBlock;
-pub use table::TableBlock;
+pub use rich_text::{Inline, RichText};
+pub use spacing::{Spacing, SpacingAxis};
+pub use table::{CellWrap, TableBlock, TableBuilder, TableShapeError};
+pub use terms_list::TermsList;
 pub use text::{Text, TextOptions};
 
+pub(crate) use label::validated_label;
+pub(crate) use lang::{lang_arguments, parse_lang};
+pub(crate) use length::length;
+pub(crate) use rich_text::footnote_label;
+
+use crate::render::RenderContext;
+
 /// Represents a renderable chunk of content that can append Typst markup to a
 /// provided output buffer.
 ///
@@ -31,6 +63,61 @@ pub trait Block: std::fmt::Debug {
     /// # Arguments
     /// - `output`: Mutable string that receives the rendered Typst markup.
     fn render(&self, output: &mut String);
+
+    /// Return what kind of block this is.
+    ///
+    /// The default reports [`BlockKind::Other`] with the implementing type's
+    /// name; every block in this crate overrides it.
+    fn kind(&self) -> BlockKind {
+        BlockKind::Other(std::any::type_name::<Self>())
+    }
+
+    /// Render the block to the provided string buffer as part of a report,
+    /// honoring its document-level settings.
+    ///
+    /// The default implementation ignores the context and calls
+    /// [`Block::render`]; blocks containing [`Text`] override it so that
+    /// document-wide text defaults from [`RenderContext::settings`] apply.
+    ///
+    /// # Arguments
+    /// - `output`: Mutable string that receives the rendered Typst markup.
+    /// - `context`: The current render, carrying the report's settings.
+    fn render_with(&self, output: &mut String, _context: &RenderContext) {
+        self.render(output);
+    }
+
+    /// Return the images embedded in this block.
+    ///
+    /// Blocks that display images override this so report-level checks can
+    /// inspect image paths without knowing the concrete block type.
+    fn images(&self) -> Vec<&Image> {
+        Vec::new()
+    }
+
+    /// Return the `<label>` names attached by this block.
+    ///
+    /// Labelled blocks override this so the report can reject duplicate labels
+    /// before Typst does at compile time.
+    fn labels(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Return the labels of the footnotes this block references.
+    ///
+    /// Blocks containing [`RichText`] override this so the report can reject
+    /// references to footnotes that were never defined.
+    fn footnote_refs(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Return the language tags set on text in this block.
+    ///
+    /// Blocks containing [`Text`] override this so the report can reject
+    /// tags that are not plausible language codes before Typst does at
+    /// compile time.
+    fn langs(&self) -> Vec<&str> {
+        Vec::new()
+    }
 }
 
 pub type BlockNode = Box<dyn Block>;
@@ -55,24 +142,90 @@ pub fn text_with_options<T: Into<String>>(content: T, options: TextOptions) -> T
 /// Wrap text content in a paragraph block.
 ///
 /// # Arguments
-/// - `text`: Content to place inside the paragraph.
-pub fn paragraph<T: Into<Text>>(text: T) -> BlockNode {
+/// - `text`: Content to place inside the paragraph, either a single [`Text`]
+///   or [`RichText`] combining several inline runs.
+pub fn paragraph<T: Into<RichText>>(text: T) -> BlockNode {
     Box::new(Paragraph::new(text))
 }
 
+/// Reference a footnote registered with
+/// [`Report::define_footnote`](crate::Report::define_footnote).
+///
+/// Combine it with other runs through [`RichText::footnote_ref`] when the
+/// reference follows text in the same paragraph.
+///
+/// # Arguments
+/// - `key`: Key the footnote was registered under.
+pub fn footnote_ref<K: Into<String>>(key: K) -> RichText {
+    RichText::new().footnote_ref(key)
+}
+
+/// Create an inline monospace code span, such as `code_span("--verbose")`.
+///
+/// Combine it with other runs through [`RichText::code`] when the span sits
+/// inside a sentence.
+///
+/// # Arguments
+/// - `content`: Code to display verbatim.
+pub fn code_span<T: Into<String>>(content: T) -> RichText {
+    RichText::new().code(content)
+}
+
+/// Create an inline run with a line through it, such as `strike("$10")`.
+///
+/// Combine it with other runs through [`RichText::strike`] when the run sits
+/// inside a sentence.
+///
+/// # Arguments
+/// - `content`: Text to strike through. Plain strings are escaped.
+pub fn strike<T: Into<Text>>(content: T) -> RichText {
+    RichText::new().strike(content)
+}
+
+/// Create an underlined inline run, such as `underline("required")`.
+///
+/// Combine it with other runs through [`RichText::underline`] when the run
+/// sits inside a sentence.
+///
+/// # Arguments
+/// - `content`: Text to underline. Plain strings are escaped.
+pub fn underline<T: Into<Text>>(content: T) -> RichText {
+    RichText::new().underline(content)
+}
+
+/// Pull in the content of another Typst file, such as a chapter kept in a
+/// file of its own, with `#include "path"`.
+///
+/// # Arguments
+/// - `path`: File to include, relative to the generated `.typ` file.
+pub fn include_file<P: Into<String>>(path: P) -> BlockNode {
+    Box::new(IncludeBlock::new(path))
+}
+
 /// Build a bulleted list from the provided items.
 ///
 /// # Arguments
-/// - `items`: Iterator of bullet contents.
-pub fn bullets<T: Into<String>>(items: impl IntoIterator<Item = T>) -> BlockNode {
+/// - `items`: Iterator of bullet contents. Plain strings are escaped, while
+///   styled [`Text`] renders as a `#text(...)` call.
+pub fn bullets<T: Into<Text>>(items: impl IntoIterator<Item = T>) -> BlockNode {
     Box::new(BulletList::new(items))
 }
 
+/// Build a list of terms with their descriptions, such as abbreviations.
+///
+/// # Arguments
+/// - `items`: Iterator of `(term, description)` pairs. Plain strings are
+///   escaped, while styled [`Text`] renders as a `#text(...)` call.
+pub fn terms<T: Into<Text>, D: Into<Text>>(items: impl IntoIterator<Item = (T, D)>) -> BlockNode {
+    Box::new(TermsList::new(items))
+}
+
 /// Build a numbered list from the provided items.
 ///
 /// # Arguments
-/// - `items`: Iterator of list entries to number.
-pub fn numbered<T: Into<String>>(items: impl IntoIterator<Item = T>) -> BlockNode {
+/// - `items`: Iterator of list entries to number. Plain strings are escaped,
+///   while styled [`Text`] renders as a `#text(...)` call.
+pub fn numbered<T: Into<Text>>(items: impl IntoIterator<Item = T>) -> BlockNode {
     Box::new(NumberedList::new(items))
 }
 
@@ -101,6 +254,39 @@ pub fn figure(body: impl Into<FigureBody>) -> Figure {
     Figure::new(body)
 }
 
+/// Lay out several images in a grid that shares sizing and spacing.
+///
+/// # Arguments
+/// - `images`: Images to place in the grid, filled row by row.
+pub fn gallery<I: Into<Image>>(images: impl IntoIterator<Item = I>) -> Gallery {
+    Gallery::new(images)
+}
+
+/// Lay out key/value pairs, such as `("Author:", "Ada")`, as a two-column
+/// grid with bold keys.
+///
+/// # Arguments
+/// - `entries`: Iterator of `(key, value)` pairs. Both are escaped.
+pub fn metadata<K: Into<String>, V: Into<String>>(
+    entries: impl IntoIterator<Item = (K, V)>,
+) -> BlockNode {
+    Box::new(MetadataBlock::new(entries))
+}
+
+/// Force the following content into the next column of a multi-column
+/// layout.
+pub fn column_break() -> ColumnBreak {
+    ColumnBreak::new()
+}
+
+/// Create a block quotation; add an attribution with [`Quote::attribution`].
+///
+/// # Arguments
+/// - `content`: Quoted text, either a single [`Text`] or [`RichText`].
+pub fn quote<T: Into<RichText>>(content: T) -> Quote {
+    Quote::new(content)
+}
+
 /// Create a hyperlink pointing to an external URL.
 ///
 /// # Arguments
@@ -113,7 +299,8 @@ pub fn link_to_url<C: Into<Text>, U: Into<String>>(url: U, content: C) -> BlockN
 /// Create an internal document link pointing to a Typst location.
 ///
 /// # Arguments
-/// - `location`: Location anchor within the Typst document.
+/// - `location`: Typst expression for the destination, such as a label
+///   `"<introduction>"`, passed to `link` unquoted.
 /// - `content`: Visible link text.
 pub fn link_to_location<C: Into<Text>, L: Into<String>>(location: L, content: C) -> BlockNode {
     Box::new(Link::to_location(location, content))
@@ -136,6 +323,26 @@ where
     Box::new(TableBlock::new(headers, rows))
 }
 
+/// Insert a fixed vertical gap between blocks, such as `vspace("2em")`.
+///
+/// # Arguments
+/// - `amount`: Typst length of the gap.
+pub fn vspace<T: Into<String>>(amount: T) -> BlockNode {
+    Box::new(Spacing::new(SpacingAxis::Vertical, amount))
+}
+
+/// Create an inline horizontal gap, such as `hspace("1cm")` between two
+/// items of a page header.
+///
+/// Combine it with other runs through [`RichText::hspace`] so the gap sits
+/// within the same line.
+///
+/// # Arguments
+/// - `amount`: Typst length of the gap.
+pub fn hspace<T: Into<String>>(amount: T) -> RichText {
+    RichText::new().hspace(amount)
+}
+
 /// Insert raw Typst content without escaping or additional formatting.
 ///
 /// # Arguments
@@ -144,6 +351,32 @@ pub fn raw<T: Into<String>>(content: T) -> BlockNode {
     Box::new(RawBlock::new(content))
 }
 
+/// Insert content verbatim as literal text, escaping Typst markup
+/// metacharacters.
+///
+/// Unlike [`raw`], the content is never interpreted as Typst, which makes this
+/// suitable for partially trusted strings.
+///
+/// # Arguments
+/// - `content`: Text to display exactly as written.
+pub fn raw_escaped<T: Into<String>>(content: T) -> BlockNode {
+    Box::new(RawBlock::escaped(content))
+}
+
+#[cfg(feature = "qr")]
+/// Create a QR code for the given data, such as a link back to a web page.
+///
+/// Use [`QrCode::new`] to set options such as the printed size.
+///
+/// # Arguments
+/// - `data`: Text to encode.
+///
+/// # Panics
+/// Panics if the data is too long to fit in a QR code.
+pub fn qr_code<T: Into<String>>(data: T) -> BlockNode {
+    Box::new(QrCode::new(data))
+}
+
 #[cfg(feature = "polars")]
 /// Render a Polars data frame as a table block.
 ///
@@ -1,26 +1,136 @@
-use super::Block;
+use super::{Block, BlockKind, Text};
+use crate::render::RenderContext;
 
 #[derive(Debug, Clone)]
 pub struct NumberedList {
-    items: Vec<String>,
+    items: Vec<Text>,
+    start: Option<usize>,
+    continue_previous: bool,
 }
 
 impl NumberedList {
-    pub fn new<T: Into<String>>(items: impl IntoIterator<Item = T>) -> Self {
+    pub fn new<T: Into<Text>>(items: impl IntoIterator<Item = T>) -> Self {
         Self {
             items: items.into_iter().map(Into::into).collect(),
+            start: None,
+            continue_previous: false,
+        }
+    }
+
+    /// Number the first item `start` instead of `1`.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Continue numbering after the last item of the previous numbered list in
+    /// the report, even when other blocks sit in between. Defaults to `false`.
+    ///
+    /// An explicit [`NumberedList::start`] takes precedence.
+    pub fn continue_previous(mut self, continue_previous: bool) -> Self {
+        self.continue_previous = continue_previous;
+        self
+    }
+
+    /// Resolve the number of the first item, or `None` for Typst's default.
+    ///
+    /// # Arguments
+    /// - `context`: Render state tracking the previous list.
+    fn first_number(&self, context: &RenderContext) -> Option<usize> {
+        match (self.start, self.continue_previous) {
+            (Some(start), _) => Some(start),
+            (None, true) => Some(context.last_list_number() + 1),
+            (None, false) => None,
         }
     }
 }
 
 impl Block for NumberedList {
+    fn kind(&self) -> BlockKind {
+        BlockKind::NumberedList
+    }
+
     fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
         use std::fmt::Write;
 
-        for item in &self.items {
-            writeln!(output, "+ {}", item.trim()).expect("writing to string never fails");
+        let settings = context.settings();
+        let first_number = self.first_number(context);
+
+        match first_number {
+            Some(start) => {
+                write!(output, "#enum(start: {}", start).expect("writing to string never fails");
+                for item in &self.items {
+                    write!(output, ", [{}]", item.render_markup(settings))
+                        .expect("writing to string never fails");
+                }
+                writeln!(output, ")").expect("writing to string never fails");
+            }
+            None => {
+                for item in &self.items {
+                    writeln!(output, "+ {}", item.render_markup(settings))
+                        .expect("writing to string never fails");
+                }
+            }
         }
 
+        if !self.items.is_empty() {
+            context.set_last_list_number(first_number.unwrap_or(1) + self.items.len() - 1);
+        }
         output.push('\n');
     }
+
+    fn langs(&self) -> Vec<&str> {
+        self.items.iter().filter_map(Text::lang_tag).collect()
+    }
+}
+
+impl From<NumberedList> for super::BlockNode {
+    fn from(value: NumberedList) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup_metacharacters_in_items() {
+        let mut rendered = String::new();
+
+        NumberedList::new(["Step #1", "Use *all* the_things"]).render(&mut rendered);
+
+        assert_eq!(rendered, "+ Step \\#1\n+ Use \\*all\\* the\\_things\n\n");
+    }
+
+    #[test]
+    fn renders_styled_items_as_text_calls() {
+        let mut rendered = String::new();
+
+        NumberedList::new([
+            Text::new("Plain [step]"),
+            Text::new("Urgent").fill(crate::Color::Red),
+        ])
+        .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "+ Plain \\[step\\]\n+ #text(\"Urgent\", fill: red)\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_explicit_start() {
+        let mut rendered = String::new();
+
+        NumberedList::new(["Third", "Fourth"])
+            .start(3)
+            .render(&mut rendered);
+
+        assert_eq!(rendered, "#enum(start: 3, [Third], [Fourth])\n\n");
+    }
 }
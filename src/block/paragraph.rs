@@ -1,12 +1,13 @@
-use super::{Block, Text};
+use super::{Block, BlockKind, RichText};
+use crate::render::RenderContext;
 
 #[derive(Debug, Clone)]
 pub struct Paragraph {
-    content: Text,
+    content: RichText,
 }
 
 impl Paragraph {
-    pub fn new<T: Into<Text>>(content: T) -> Self {
+    pub fn new<T: Into<RichText>>(content: T) -> Self {
         Self {
             content: content.into(),
         }
@@ -14,10 +15,28 @@ impl Paragraph {
 }
 
 impl Block for Paragraph {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Paragraph
+    }
+
     fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
         use std::fmt::Write;
 
-        writeln!(output, "{}", self.content.render()).expect("writing to string never fails");
+        let settings = context.settings();
+        writeln!(output, "{}", self.content.render_with(settings))
+            .expect("writing to string never fails");
         output.push('\n');
     }
+
+    fn footnote_refs(&self) -> Vec<&str> {
+        self.content.footnote_refs()
+    }
+
+    fn langs(&self) -> Vec<&str> {
+        self.content.langs()
+    }
 }
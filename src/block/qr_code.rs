@@ -0,0 +1,80 @@
+use qrcode::{QrCode as Encoder, render::svg};
+
+use super::{Block, BlockKind};
+use crate::typst_escape::escape_string;
+
+/// A QR code encoding text such as a URL, embedded as an SVG image.
+#[derive(Debug, Clone)]
+pub struct QrCode {
+    svg: String,
+    size: Option<String>,
+}
+
+impl QrCode {
+    /// Encode the data as a QR code.
+    ///
+    /// # Panics
+    /// Panics if the data is too long to fit in a QR code.
+    pub fn new<T: Into<String>>(data: T) -> Self {
+        let data = data.into();
+        let code = Encoder::new(data.as_bytes())
+            .unwrap_or_else(|err| panic!("cannot encode {data:?} as a QR code: {err}"));
+
+        Self {
+            svg: code.render::<svg::Color<'_>>().build(),
+            size: None,
+        }
+    }
+
+    /// Set the printed width and height of the code, such as `"3cm"`.
+    ///
+    /// When unset, the code keeps the size of the generated SVG.
+    pub fn size<T: Into<String>>(mut self, size: T) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl Block for QrCode {
+    fn kind(&self) -> BlockKind {
+        BlockKind::QrCode
+    }
+
+    fn render(&self, output: &mut String) {
+        output.push_str("#image.decode(\"");
+        output.push_str(&escape_string(&self.svg));
+        output.push_str("\", format: \"svg\"");
+
+        if let Some(size) = &self.size {
+            output.push_str(", width: ");
+            output.push_str(size);
+        }
+
+        output.push_str(")\n\n");
+    }
+}
+
+impl From<QrCode> for super::BlockNode {
+    fn from(value: QrCode) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embeds_svg_through_image_decode() {
+        let mut rendered = String::new();
+
+        QrCode::new("https://example.com/report")
+            .size("3cm")
+            .render(&mut rendered);
+
+        assert!(rendered.starts_with("#image.decode(\"<?xml"));
+        assert!(rendered.contains("<svg"));
+        assert!(rendered.ends_with("\", format: \"svg\", width: 3cm)\n\n"));
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Block, BlockKind, RichText, label::validated_label};
+use crate::{render::RenderContext, typst_escape::escape_string};
+
+use std::fmt::Write;
+
+/// Who or what a [`Quote`] is attributed to.
+#[derive(Debug, Clone)]
+pub enum Attribution {
+    /// A plain name, rendered as a quoted string.
+    Text(String),
+    /// A bibliography key, rendered as `cite(<key>)`.
+    Cite(String),
+}
+
+impl Attribution {
+    /// Attribute the quote to a bibliography entry.
+    ///
+    /// # Panics
+    /// Panics if the key is not a valid Typst label.
+    pub fn cite<K: Into<String>>(key: K) -> Self {
+        Attribution::Cite(validated_label(key.into()))
+    }
+}
+
+impl From<String> for Attribution {
+    fn from(value: String) -> Self {
+        Attribution::Text(value)
+    }
+}
+
+impl From<&str> for Attribution {
+    fn from(value: &str) -> Self {
+        Attribution::Text(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Attribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Attribution::Text(name) => write!(f, "\"{}\"", escape_string(name)),
+            Attribution::Cite(key) => write!(f, "cite(<{}>)", key),
+        }
+    }
+}
+
+/// A block quotation with an optional attribution.
+#[derive(Debug, Clone)]
+pub struct Quote {
+    content: RichText,
+    attribution: Option<Attribution>,
+}
+
+impl Quote {
+    pub fn new<T: Into<RichText>>(content: T) -> Self {
+        Self {
+            content: content.into(),
+            attribution: None,
+        }
+    }
+
+    /// Attribute the quote to a plain name or, with [`Attribution::cite`], a
+    /// bibliography entry.
+    pub fn attribution<A: Into<Attribution>>(mut self, attribution: A) -> Self {
+        self.attribution = Some(attribution.into());
+        self
+    }
+}
+
+impl Block for Quote {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Quote
+    }
+
+    fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
+        let settings = context.settings();
+        output.push_str("#quote(block: true");
+
+        if let Some(attribution) = &self.attribution {
+            write!(output, ", attribution: {}", attribution)
+                .expect("writing to string never fails");
+        }
+
+        writeln!(output, ")[{}]", self.content.render_with(settings))
+            .expect("writing to string never fails");
+        output.push('\n');
+    }
+
+    fn footnote_refs(&self) -> Vec<&str> {
+        self.content.footnote_refs()
+    }
+
+    fn langs(&self) -> Vec<&str> {
+        self.content.langs()
+    }
+}
+
+impl From<Quote> for super::BlockNode {
+    fn from(value: Quote) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_cited_attribution() {
+        let mut rendered = String::new();
+
+        Quote::new("Simplicity is prerequisite for reliability.")
+            .attribution(Attribution::cite("dijkstra1975"))
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#quote(block: true, attribution: cite(<dijkstra1975>))[Simplicity is prerequisite for reliability.]\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_plain_attribution_as_quoted_string() {
+        let mut rendered = String::new();
+
+        Quote::new("Ship it.")
+            .attribution("The \"Team\"")
+            .render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "#quote(block: true, attribution: \"The \\\"Team\\\"\")[Ship it.]\n\n"
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use super::Block;
+use super::{Block, BlockKind};
+use crate::typst_escape::escape_markup;
 
 #[derive(Debug, Clone)]
 pub struct RawBlock {
@@ -11,9 +12,19 @@ impl RawBlock {
             content: content.into(),
         }
     }
+
+    /// Create a block whose content renders as literal text, with Typst
+    /// markup metacharacters such as `#` and `[` escaped.
+    pub fn escaped<T: Into<String>>(content: T) -> Self {
+        Self::new(escape_markup(&content.into()))
+    }
 }
 
 impl Block for RawBlock {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Raw
+    }
+
     fn render(&self, output: &mut String) {
         use std::fmt::Write;
 
@@ -21,3 +32,62 @@ impl Block for RawBlock {
         output.push('\n');
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaped_content_renders_literally() {
+        let mut output = String::new();
+
+        RawBlock::escaped("#set text(red)").render(&mut output);
+
+        assert_eq!(output, "\\#set text(red)\n\n");
+        assert!(crate::validate_markup(&output).is_ok());
+    }
+
+    /// Return whether the markup parses to any node other than plain text,
+    /// spaces, and escapes.
+    fn has_structure(markup: &str) -> bool {
+        use typst::syntax::{SyntaxKind, SyntaxNode};
+
+        fn walk(node: &SyntaxNode) -> bool {
+            !matches!(
+                node.kind(),
+                SyntaxKind::Markup
+                    | SyntaxKind::Text
+                    | SyntaxKind::Space
+                    | SyntaxKind::Parbreak
+                    | SyntaxKind::Escape
+            ) || node.children().any(walk)
+        }
+
+        walk(&typst::syntax::parse(markup))
+    }
+
+    #[test]
+    fn escaped_content_never_becomes_structure() {
+        let cases = [
+            "= Pwned",
+            "- item",
+            "+ step",
+            "  - indented item",
+            "12. entry",
+            "/ term: description",
+            "a // b",
+            "a /* b */ c",
+            "non~breaking",
+            "first line\n= second line heading",
+        ];
+        assert!(has_structure("= Pwned\n- item // note"));
+
+        for case in cases {
+            let mut output = String::new();
+
+            RawBlock::escaped(case).render(&mut output);
+
+            assert!(!has_structure(&output), "{case:?} rendered as {output:?}");
+        }
+    }
+}
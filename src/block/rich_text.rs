@@ -0,0 +1,347 @@
+use super::{Text, label::validated_label};
+use crate::render::RenderSettings;
+use crate::typst_escape::escape_string;
+
+/// A single piece of inline content within a [`RichText`] run sequence.
+#[derive(Debug, Clone)]
+pub enum Inline {
+    Text(Text),
+    Link { url: String, content: Text },
+    FootnoteRef(String),
+    Code(String),
+    Strike(Text),
+    Underline(Text),
+    HSpace(String),
+}
+
+/// Inline content made of several runs that render as one flowing block, such
+/// as a sentence containing a hyperlink.
+///
+/// Runs are concatenated exactly as given, so include any separating spaces in
+/// the surrounding text runs. Styled runs and links stay self-contained even
+/// when the next run starts with punctuation such as `(` or `.`.
+#[derive(Debug, Clone, Default)]
+pub struct RichText {
+    runs: Vec<Inline>,
+}
+
+impl RichText {
+    /// Create an empty run sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a text run.
+    ///
+    /// Unlike standalone text, runs keep their leading and trailing whitespace
+    /// unless [`Text::preserve_whitespace`] was set explicitly.
+    pub fn text<T: Into<Text>>(mut self, text: T) -> Self {
+        self.runs
+            .push(Inline::Text(text.into().preserve_whitespace_by_default()));
+        self
+    }
+
+    /// Append an inline hyperlink to an external URL.
+    pub fn link<U: Into<String>, C: Into<Text>>(mut self, url: U, content: C) -> Self {
+        self.runs.push(Inline::Link {
+            url: url.into(),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Append a reference to a footnote registered with
+    /// [`Report::define_footnote`](crate::Report::define_footnote).
+    ///
+    /// Every reference to the same key shares one footnote number and entry.
+    ///
+    /// # Panics
+    /// Panics if the key does not form a valid Typst label.
+    pub fn footnote_ref<K: Into<String>>(mut self, key: K) -> Self {
+        self.runs
+            .push(Inline::FootnoteRef(footnote_label(key.into())));
+        self
+    }
+
+    /// Append an inline monospace code span, such as a value or identifier
+    /// mentioned in a sentence.
+    ///
+    /// The content renders verbatim inside backticks; content that contains
+    /// backticks itself gets a longer fence.
+    pub fn code<T: Into<String>>(mut self, content: T) -> Self {
+        self.runs.push(Inline::Code(content.into()));
+        self
+    }
+
+    /// Append a run with a line through it, such as a retracted figure.
+    ///
+    /// Unlike plain text runs, plain content is escaped so it renders
+    /// literally.
+    pub fn strike<T: Into<Text>>(mut self, content: T) -> Self {
+        self.runs.push(Inline::Strike(
+            content.into().preserve_whitespace_by_default(),
+        ));
+        self
+    }
+
+    /// Append an underlined run.
+    ///
+    /// Unlike plain text runs, plain content is escaped so it renders
+    /// literally.
+    pub fn underline<T: Into<Text>>(mut self, content: T) -> Self {
+        self.runs.push(Inline::Underline(
+            content.into().preserve_whitespace_by_default(),
+        ));
+        self
+    }
+
+    /// Append a fixed horizontal gap, such as `1cm`, or `1fr` to push the
+    /// following runs to the end of the line.
+    pub fn hspace<T: Into<String>>(mut self, amount: T) -> Self {
+        self.runs.push(Inline::HSpace(amount.into()));
+        self
+    }
+
+    /// Return the labels of the footnotes referenced by the runs.
+    pub(crate) fn footnote_refs(&self) -> Vec<&str> {
+        self.runs
+            .iter()
+            .filter_map(|run| match run {
+                Inline::FootnoteRef(label) => Some(label.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Return the language tags set on the text runs.
+    pub(crate) fn langs(&self) -> Vec<&str> {
+        self.runs
+            .iter()
+            .filter_map(|run| match run {
+                Inline::Text(text)
+                | Inline::Link { content: text, .. }
+                | Inline::Strike(text)
+                | Inline::Underline(text) => text.lang_tag(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Render all runs into a single string of Typst markup.
+    ///
+    /// # Arguments
+    /// - `settings`: Document-level defaults configured on the report.
+    pub(crate) fn render_with(&self, settings: &RenderSettings) -> String {
+        let mut output = String::new();
+        let mut after_call = false;
+
+        for rendered in self.runs.iter().map(|run| run.render_with(settings)) {
+            if rendered.is_empty() {
+                continue;
+            }
+
+            // A `(`, `[` or `.` directly after an embedded call would extend
+            // it with arguments or a field access; `;` ends the call without
+            // rendering anything.
+            if after_call && rendered.starts_with(['(', '[', '.']) {
+                output.push(';');
+            }
+            after_call = rendered.starts_with('#');
+            output.push_str(&rendered);
+        }
+
+        output
+    }
+}
+
+impl Inline {
+    /// Render the run as inline Typst markup.
+    ///
+    /// # Arguments
+    /// - `settings`: Document-level defaults configured on the report.
+    fn render_with(&self, settings: &RenderSettings) -> String {
+        match self {
+            Inline::Text(text) => text.render_with(settings),
+            Inline::Link { url, content } => format!(
+                "#link(\"{}\")[{}]",
+                escape_string(url),
+                content.render_with(settings)
+            ),
+            Inline::FootnoteRef(label) => format!("#footnote(<{}>)", label),
+            Inline::Code(content) => code_span_markup(content),
+            Inline::Strike(content) => format!("#strike[{}]", content.render_markup(settings)),
+            Inline::Underline(content) => {
+                format!("#underline[{}]", content.render_markup(settings))
+            }
+            Inline::HSpace(amount) => format!("#h({})", amount),
+        }
+    }
+}
+
+/// Wrap content in an inline raw span using a backtick fence it cannot close.
+///
+/// Content without backticks uses a single backtick. Otherwise the fence is
+/// longer than the longest run of backticks in the content and at least three
+/// long, since two backticks form an empty span. A leading space keeps the
+/// content from being read as a language tag, and a trailing space separates
+/// a final backtick from the fence; Typst trims both.
+fn code_span_markup(content: &str) -> String {
+    let longest_run = content
+        .split(|ch| ch != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+
+    if longest_run == 0 {
+        format!("`{}`", content)
+    } else {
+        let fence = "`".repeat((longest_run + 1).max(3));
+        let trailing = if content.ends_with('`') { " " } else { "" };
+        format!("{fence} {content}{trailing}{fence}")
+    }
+}
+
+/// Build the label of the footnote registered under `key`.
+///
+/// # Arguments
+/// - `key`: Footnote key chosen by the caller.
+///
+/// # Panics
+/// Panics if the key does not form a valid Typst label.
+pub(crate) fn footnote_label(key: String) -> String {
+    validated_label(format!("{}-note", key))
+}
+
+impl From<Text> for RichText {
+    fn from(value: Text) -> Self {
+        Self {
+            runs: vec![Inline::Text(value)],
+        }
+    }
+}
+
+impl From<String> for RichText {
+    fn from(value: String) -> Self {
+        Self::from(Text::from(value))
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(value: &str) -> Self {
+        Self::from(Text::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::{Block, Paragraph};
+
+    #[test]
+    fn renders_embedded_link_within_one_paragraph() {
+        let mut rendered = String::new();
+        let content = RichText::new()
+            .text("See ")
+            .link("https://docs.rs", "the docs")
+            .text(" for details.");
+
+        Paragraph::new(content).render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "See #link(\"https://docs.rs\")[the docs] for details.\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_footnote_reference_to_labelled_note() {
+        let rendered = RichText::new()
+            .text("Revenue grew")
+            .footnote_ref("audited")
+            .render_with(&RenderSettings::default());
+
+        assert_eq!(rendered, "Revenue grew#footnote(<audited-note>)");
+    }
+
+    #[test]
+    fn renders_code_span_with_backtick_delimiters() {
+        let rendered = RichText::new()
+            .text("Set ")
+            .code("retries = 3")
+            .text(" to retry.")
+            .render_with(&RenderSettings::default());
+
+        assert_eq!(rendered, "Set `retries = 3` to retry.");
+    }
+
+    #[test]
+    fn wraps_strike_and_underline_runs_around_escaped_content() {
+        let settings = RenderSettings::default();
+
+        assert_eq!(
+            RichText::new().strike("$10 [old]").render_with(&settings),
+            "#strike[\\$10 \\[old\\]]"
+        );
+        assert_eq!(
+            RichText::new().underline("*must*").render_with(&settings),
+            "#underline[\\*must\\*]"
+        );
+        assert_eq!(
+            RichText::new()
+                .text("Price: ")
+                .strike("$10")
+                .text(" now ")
+                .underline("$8")
+                .render_with(&settings),
+            "Price: #strike[\\$10] now #underline[\\$8]"
+        );
+    }
+
+    #[test]
+    fn renders_horizontal_space_between_words_of_one_paragraph() {
+        let mut rendered = String::new();
+        let content = RichText::new().text("Left").hspace("1cm").text("Right");
+
+        Paragraph::new(content).render(&mut rendered);
+
+        assert_eq!(rendered, "Left#h(1cm)Right\n\n");
+    }
+
+    #[test]
+    fn code_span_containing_backticks_uses_longer_fence() {
+        let single = RichText::new()
+            .code("a`b")
+            .render_with(&RenderSettings::default());
+        let triple = RichText::new()
+            .code("```")
+            .render_with(&RenderSettings::default());
+
+        assert_eq!(single, "``` a`b```");
+        assert_eq!(triple, "```` ``` ````");
+        assert!(crate::validate_markup(&single).is_ok());
+    }
+
+    #[test]
+    fn ends_calls_before_runs_that_would_extend_them() {
+        let rendered = RichText::new()
+            .text(Text::new("Total").fill(crate::Color::Red))
+            .text("(net)")
+            .link("https://docs.rs", "docs")
+            .text(".")
+            .strike("old")
+            .text("[sic]")
+            .render_with(&RenderSettings::default());
+
+        assert_eq!(
+            rendered,
+            "#text(\"Total\", fill: red);(net)#link(\"https://docs.rs\")[docs];.#strike[old];[sic]"
+        );
+    }
+
+    #[test]
+    fn single_text_conversion_keeps_standard_trimming() {
+        let rendered = RichText::from("  trimmed  ").render_with(&RenderSettings::default());
+
+        assert_eq!(rendered, "trimmed");
+    }
+}
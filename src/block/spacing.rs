@@ -0,0 +1,73 @@
+use super::{Block, BlockKind};
+
+use std::fmt::Write;
+
+/// Direction of a [`Spacing`] block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpacingAxis {
+    /// Vertical space between blocks, rendered as `#v(..)`.
+    Vertical,
+    /// Horizontal space within a line, rendered as `#h(..)`.
+    Horizontal,
+}
+
+/// An explicit gap of a fixed size, such as `2em` or `1cm`.
+#[derive(Debug, Clone)]
+pub struct Spacing {
+    axis: SpacingAxis,
+    amount: String,
+}
+
+impl Spacing {
+    pub fn new<T: Into<String>>(axis: SpacingAxis, amount: T) -> Self {
+        Self {
+            axis,
+            amount: amount.into(),
+        }
+    }
+}
+
+impl Block for Spacing {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Spacing
+    }
+
+    fn render(&self, output: &mut String) {
+        let function = match self.axis {
+            SpacingAxis::Vertical => "v",
+            SpacingAxis::Horizontal => "h",
+        };
+
+        writeln!(output, "#{}({})", function, self.amount).expect("writing to string never fails");
+        output.push('\n');
+    }
+}
+
+impl From<Spacing> for super::BlockNode {
+    fn from(value: Spacing) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_vertical_space() {
+        let mut rendered = String::new();
+
+        Spacing::new(SpacingAxis::Vertical, "2em").render(&mut rendered);
+
+        assert_eq!(rendered, "#v(2em)\n\n");
+    }
+
+    #[test]
+    fn renders_horizontal_space() {
+        let mut rendered = String::new();
+
+        Spacing::new(SpacingAxis::Horizontal, "1cm").render(&mut rendered);
+
+        assert_eq!(rendered, "#h(1cm)\n\n");
+    }
+}
@@ -1,14 +1,57 @@
-use super::Block;
+use super::{Block, BlockKind};
 
 use crate::render::table::table_markup;
 
+#[cfg(feature = "polars")]
+use crate::number_format::NumberFormat;
+
+/// Highlight behind the cells of rows that only exist in the new table.
+const ADDED_FILL: &str = "green.lighten(70%)";
+/// Highlight behind the cells of rows that only exist in the old table.
+const REMOVED_FILL: &str = "red.lighten(70%)";
+/// Highlight behind cells whose value differs between the two tables.
+const CHANGED_FILL: &str = "yellow.lighten(40%)";
+
 #[derive(Debug, Clone)]
 pub struct TableBlock {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
+    repeat_header: bool,
+    stroke: Option<String>,
+    gutter: Option<String>,
+    column_wraps: Vec<(usize, CellWrap)>,
+}
+
+/// How the text of a table column breaks across lines.
+///
+/// Columns without a setting wrap like ordinary paragraphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellWrap {
+    /// Keep each cell on a single line, such as for IDs or dates.
+    NoWrap,
+    /// Wrap at spaces but never hyphenate words.
+    NoHyphenate,
+    /// Wrap with a ragged right edge even when paragraphs are justified.
+    Ragged,
+}
+
+impl CellWrap {
+    /// Wrap cell markup in the container that applies this behavior.
+    pub(crate) fn apply(self, content: &str) -> String {
+        match self {
+            CellWrap::NoWrap => format!("#box[{}]", content),
+            CellWrap::NoHyphenate => format!("#text(hyphenate: false)[{}]", content),
+            CellWrap::Ragged => format!("#par(justify: false)[{}]", content),
+        }
+    }
 }
 
 impl TableBlock {
+    /// Create a table from headers and rows of cell values.
+    ///
+    /// Every row is fitted to the number of headers: short rows are padded
+    /// with empty cells and extra cells in long rows are dropped, so the
+    /// rendered table is never ragged.
     pub fn new<H, R, C>(
         headers: impl IntoIterator<Item = H>,
         rows: impl IntoIterator<Item = R>,
@@ -18,18 +61,51 @@ impl TableBlock {
         R: IntoIterator<Item = C>,
         C: Into<String>,
     {
+        let headers = headers.into_iter().map(Into::into).collect::<Vec<String>>();
+        let width = headers.len();
+
         Self {
-            headers: headers.into_iter().map(Into::into).collect(),
             rows: rows
                 .into_iter()
-                .map(|row| row.into_iter().map(Into::into).collect())
+                .map(|row| {
+                    let mut cells = row
+                        .into_iter()
+                        .map(Into::into)
+                        .take(width)
+                        .collect::<Vec<String>>();
+                    cells.resize(width, String::new());
+                    cells
+                })
                 .collect(),
+            headers,
+            repeat_header: false,
+            stroke: None,
+            gutter: None,
+            column_wraps: Vec::new(),
         }
     }
 
     #[cfg(feature = "polars")]
     pub fn from_polars_dataframe(
         dataframe: &polars::prelude::DataFrame,
+    ) -> polars::prelude::PolarsResult<Self> {
+        Self::from_polars(dataframe, None)
+    }
+
+    /// Build a table from a dataframe, rendering integer and floating-point
+    /// cells with the given separators, such as `1.234,5`.
+    #[cfg(feature = "polars")]
+    pub fn from_polars_dataframe_with_format(
+        dataframe: &polars::prelude::DataFrame,
+        format: &NumberFormat,
+    ) -> polars::prelude::PolarsResult<Self> {
+        Self::from_polars(dataframe, Some(format))
+    }
+
+    #[cfg(feature = "polars")]
+    fn from_polars(
+        dataframe: &polars::prelude::DataFrame,
+        format: Option<&NumberFormat>,
     ) -> polars::prelude::PolarsResult<Self> {
         let headers = dataframe
             .get_column_names()
@@ -42,16 +118,219 @@ impl TableBlock {
             let mut row = Vec::with_capacity(dataframe.width());
             for column in dataframe.get_columns() {
                 let value = column.get(row_idx)?;
-                row.push(value.to_string());
+                row.push(format_polars_value(&value, format));
             }
             rows.push(row);
         }
 
-        Ok(Self { headers, rows })
+        Ok(Self {
+            headers,
+            rows,
+            repeat_header: false,
+            stroke: None,
+            gutter: None,
+            column_wraps: Vec::new(),
+        })
+    }
+
+    /// Compare two versions of a table, such as this week's figures against
+    /// last week's, matching rows by their cell in `key_column`.
+    ///
+    /// The result starts with a "Status" column marking each row `added`,
+    /// `removed`, `changed`, or `unchanged`. Cells of added rows are
+    /// highlighted green, cells of removed rows red, and changed cells
+    /// yellow. Rows follow the order of `new`, then removed rows in the order
+    /// of `old`. Cells are compared by position under the headers of `new`.
+    ///
+    /// # Panics
+    /// Panics if `key_column` is not a column of both tables.
+    pub fn diff(old: &TableBlock, new: &TableBlock, key_column: usize) -> TableBlock {
+        assert!(
+            key_column < old.headers.len() && key_column < new.headers.len(),
+            "key column {} is not a column of both tables",
+            key_column
+        );
+
+        let highlight = |cell: &str, fill: &str| format!("#highlight(fill: {})[{}]", fill, cell);
+        let find_row = |rows: &[Vec<String>], key: &str| {
+            rows.iter()
+                .find(|row| row[key_column].trim() == key.trim())
+                .cloned()
+        };
+
+        let mut rows = Vec::with_capacity(new.rows.len());
+        for row in &new.rows {
+            let (status, cells) = match find_row(&old.rows, &row[key_column]) {
+                None => (
+                    "added",
+                    row.iter().map(|cell| highlight(cell, ADDED_FILL)).collect(),
+                ),
+                Some(old_row) if old_row == *row => ("unchanged", row.clone()),
+                Some(old_row) => (
+                    "changed",
+                    row.iter()
+                        .enumerate()
+                        .map(|(idx, cell)| match old_row.get(idx) {
+                            Some(old_cell) if old_cell.trim() == cell.trim() => cell.clone(),
+                            _ => highlight(cell, CHANGED_FILL),
+                        })
+                        .collect(),
+                ),
+            };
+            rows.push(std::iter::once(status.to_string()).chain(cells).collect());
+        }
+
+        for row in &old.rows {
+            if find_row(&new.rows, &row[key_column]).is_none() {
+                rows.push(
+                    std::iter::once("removed".to_string())
+                        .chain(row.iter().map(|cell| highlight(cell, REMOVED_FILL)))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        let headers = std::iter::once("Status".to_string())
+            .chain(new.headers.iter().cloned())
+            .collect::<Vec<_>>();
+
+        TableBlock::new(headers, rows)
+    }
+
+    /// Repeat the header row at the top of every page the table spans.
+    ///
+    /// Enabling this wraps the header cells in
+    /// `#table.header(repeat: true)[...]`.
+    pub fn repeat_header(mut self, repeat_header: bool) -> Self {
+        self.repeat_header = repeat_header;
+        self
+    }
+
+    /// Set the cell border stroke, such as `"none"` or `"0.5pt + gray"`.
+    ///
+    /// When unset, Typst's default table stroke applies.
+    pub fn stroke<T: Into<String>>(mut self, stroke: T) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Control how the cells of the zero-based `column`, including its
+    /// header, break across lines. Setting a column again replaces its
+    /// earlier setting.
+    pub fn column_wrap(mut self, column: usize, wrap: CellWrap) -> Self {
+        self.column_wraps
+            .retain(|(existing, _)| *existing != column);
+        self.column_wraps.push((column, wrap));
+        self
+    }
+
+    /// Set the space between rows and columns, such as `"4pt"`.
+    ///
+    /// When unset, cells sit directly next to each other.
+    pub fn gutter<T: Into<String>>(mut self, gutter: T) -> Self {
+        self.gutter = Some(gutter.into());
+        self
+    }
+}
+
+/// Render a dataframe cell, applying the number format to numeric values.
+#[cfg(feature = "polars")]
+fn format_polars_value(
+    value: &polars::prelude::AnyValue<'_>,
+    format: Option<&NumberFormat>,
+) -> String {
+    let dtype = value.dtype();
+    let formatted = format.and_then(|format| {
+        if dtype.is_float() {
+            value
+                .extract::<f64>()
+                .map(|number| format.format_float(number))
+        } else if dtype.is_integer() {
+            value
+                .extract::<i64>()
+                .map(|number| format.format_integer(number))
+        } else {
+            None
+        }
+    });
+
+    formatted.unwrap_or_else(|| value.to_string())
+}
+
+/// Builds a [`TableBlock`] one row at a time, such as from a loop over
+/// records.
+#[derive(Debug, Clone)]
+pub struct TableBuilder {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TableBuilder {
+    /// Start a table with the given column headers and no rows.
+    pub fn new<H: Into<String>>(headers: impl IntoIterator<Item = H>) -> Self {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row of cell values.
+    ///
+    /// # Errors
+    /// Returns a [`TableShapeError`] if the row does not have exactly one cell
+    /// per header.
+    pub fn push_row<C: Into<String>>(
+        mut self,
+        row: impl IntoIterator<Item = C>,
+    ) -> Result<Self, TableShapeError> {
+        let row = row.into_iter().map(Into::into).collect::<Vec<String>>();
+        if row.len() != self.headers.len() {
+            return Err(TableShapeError {
+                row: self.rows.len() + 1,
+                cells: row.len(),
+                headers: self.headers.len(),
+            });
+        }
+
+        self.rows.push(row);
+        Ok(self)
+    }
+
+    /// Finish the table.
+    pub fn build(self) -> TableBlock {
+        TableBlock::new(self.headers, self.rows)
     }
 }
 
+/// Error returned by [`TableBuilder::push_row`] when a row does not have one
+/// cell per header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableShapeError {
+    /// One-based position of the rejected row.
+    pub row: usize,
+    /// Number of cells in the rejected row.
+    pub cells: usize,
+    /// Number of headers in the table.
+    pub headers: usize,
+}
+
+impl std::fmt::Display for TableShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "table row {} has {} cells but the table has {} headers",
+            self.row, self.cells, self.headers
+        )
+    }
+}
+
+impl std::error::Error for TableShapeError {}
+
 impl Block for TableBlock {
+    fn kind(&self) -> BlockKind {
+        BlockKind::Table
+    }
+
     fn render(&self, output: &mut String) {
         output.push_str(&self.render_markup(true));
         output.push('\n');
@@ -59,7 +338,210 @@ impl Block for TableBlock {
 }
 
 impl TableBlock {
-    pub(crate) fn render_markup(&self, include_hash: bool) -> String {
-        table_markup(&self.headers, &self.rows, include_hash)
+    /// Render the table as a Typst expression.
+    ///
+    /// Pass `include_hash: true` to place the table inside markup, such as a
+    /// cell of another table, and `false` inside code, such as a figure body.
+    pub fn render_markup(&self, include_hash: bool) -> String {
+        table_markup(
+            &self.headers,
+            &self.rows,
+            include_hash,
+            self.repeat_header,
+            self.stroke.as_deref(),
+            self.gutter.as_deref(),
+            &self.column_wraps,
+        )
+    }
+}
+
+impl From<TableBlock> for super::BlockNode {
+    fn from(value: TableBlock) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_short_rows_to_header_count() {
+        let table = TableBlock::new(["Name", "Role", "Team"], [vec!["Ada"]]);
+
+        let rendered = table.render_markup(true);
+
+        assert!(rendered.contains("  [Ada], [], [],\n"));
+    }
+
+    #[test]
+    fn nests_table_inside_outer_cell() {
+        let inner = TableBlock::new(["Inner"], [["x"]]);
+        let outer = TableBlock::new(
+            ["Name", "Details"],
+            [vec!["Ada".to_string(), inner.render_markup(true)]],
+        );
+
+        let rendered = outer.render_markup(true);
+
+        assert!(rendered.contains("[Ada], [#table(\n  columns: (1fr,),\n"));
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+
+    #[test]
+    fn repeats_header_across_pages_when_enabled() {
+        let table = TableBlock::new(["Name", "Role"], [["Ada", "Engineer"]]).repeat_header(true);
+
+        let rendered = table.render_markup(true);
+
+        assert!(rendered.contains("  table.header(repeat: true, [Name], [Role]),\n"));
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+
+    #[test]
+    fn emits_stroke_and_gutter_when_configured() {
+        let styled = TableBlock::new(["Name"], [["Ada"]])
+            .stroke("none")
+            .gutter("4pt");
+        let plain = TableBlock::new(["Name"], [["Ada"]]);
+
+        assert!(styled.render_markup(true).starts_with(
+            "#table(\n  columns: (1fr,),\n  stroke: none,\n  gutter: 4pt,\n  [Name],\n"
+        ));
+        assert!(
+            plain
+                .render_markup(true)
+                .starts_with("#table(\n  columns: (1fr,),\n  [Name],\n")
+        );
+    }
+
+    #[test]
+    fn wraps_only_configured_columns_in_containers() {
+        let table = TableBlock::new(["ID", "Notes"], [["INV-2024-0001", "Long free text"]])
+            .column_wrap(0, CellWrap::NoWrap);
+
+        let rendered = table.render_markup(true);
+
+        assert!(rendered.contains("  [#box[ID]], [Notes],\n"));
+        assert!(rendered.contains("  [#box[INV-2024-0001]], [Long free text],\n"));
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+
+    #[test]
+    fn renders_hyphenation_and_ragged_containers() {
+        let table = TableBlock::new(["Term", "Definition"], [["Photosynthesis", "Light"]])
+            .column_wrap(0, CellWrap::Ragged)
+            .column_wrap(0, CellWrap::NoHyphenate)
+            .column_wrap(1, CellWrap::Ragged);
+
+        let rendered = table.render_markup(true);
+
+        assert!(rendered.contains(
+            "  [#text(hyphenate: false)[Photosynthesis]], [#par(justify: false)[Light]],\n"
+        ));
+    }
+
+    #[test]
+    fn diff_marks_added_removed_and_changed_rows() {
+        let old = TableBlock::new(
+            ["Region", "Sales"],
+            [["North", "120"], ["South", "80"], ["West", "50"]],
+        );
+        let new = TableBlock::new(
+            ["Region", "Sales"],
+            [["North", "120"], ["South", "95"], ["East", "30"]],
+        );
+
+        let rendered = TableBlock::diff(&old, &new, 0).render_markup(true);
+
+        assert!(rendered.starts_with("#table(\n  columns: (1fr, 1fr, 1fr),\n"));
+        assert!(rendered.contains("  [Status], [Region], [Sales],\n"));
+        assert!(rendered.contains("  [unchanged], [North], [120],\n"));
+        assert!(
+            rendered
+                .contains("  [changed], [South], [#highlight(fill: yellow.lighten(40%))[95]],\n")
+        );
+        assert!(rendered.contains(
+            "  [added], [#highlight(fill: green.lighten(70%))[East]], [#highlight(fill: green.lighten(70%))[30]],\n"
+        ));
+        assert!(rendered.ends_with(
+            "  [removed], [#highlight(fill: red.lighten(70%))[West]], [#highlight(fill: red.lighten(70%))[50]],\n)\n"
+        ));
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn formats_numeric_dataframe_cells_with_number_format() {
+        use polars::prelude::*;
+
+        let dataframe = df!(
+            "Amount" => [1234.5f64, -0.25],
+            "Count" => [1_000_000i64, 7],
+        )
+        .expect("dataframe should build");
+
+        let formatted =
+            TableBlock::from_polars_dataframe_with_format(&dataframe, &NumberFormat::new(',', '.'))
+                .expect("dataframe should convert");
+        let plain =
+            TableBlock::from_polars_dataframe(&dataframe).expect("dataframe should convert");
+
+        assert_eq!(
+            formatted.rows,
+            [vec!["1.234,5", "1.000.000"], vec!["-0,25", "7"]]
+        );
+        assert_eq!(plain.rows, [vec!["1234.5", "1000000"], vec!["-0.25", "7"]]);
+    }
+
+    #[test]
+    fn builder_matches_one_shot_constructor() {
+        let rows = [
+            ["Ada", "Engineer"],
+            ["Grace", "Admiral"],
+            ["Alan", "Researcher"],
+        ];
+        let built = rows
+            .iter()
+            .try_fold(TableBuilder::new(["Name", "Role"]), |builder, row| {
+                builder.push_row(*row)
+            })
+            .expect("every row matches the headers")
+            .build();
+
+        let direct = TableBlock::new(["Name", "Role"], rows);
+
+        assert_eq!(built.render_markup(true), direct.render_markup(true));
+    }
+
+    #[test]
+    fn builder_rejects_rows_of_the_wrong_width() {
+        let result = TableBuilder::new(["Name", "Role"])
+            .push_row(["Ada", "Engineer"])
+            .and_then(|builder| builder.push_row(["Grace"]));
+
+        let error = result.expect_err("short row should be rejected");
+        assert_eq!(
+            error,
+            TableShapeError {
+                row: 2,
+                cells: 1,
+                headers: 2,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "table row 2 has 1 cells but the table has 2 headers"
+        );
+    }
+
+    #[test]
+    fn truncates_long_rows_to_header_count() {
+        let table = TableBlock::new(["Name"], [vec!["Ada", "Extra"]]);
+
+        let rendered = table.render_markup(true);
+
+        assert!(rendered.contains("  [Ada],\n"));
+        assert!(!rendered.contains("Extra"));
     }
 }
@@ -0,0 +1,85 @@
+use super::{Block, BlockKind, Text};
+use crate::render::RenderContext;
+
+/// A list of terms and their descriptions, such as abbreviations and what
+/// they stand for.
+#[derive(Debug, Clone)]
+pub struct TermsList {
+    items: Vec<(Text, Text)>,
+}
+
+impl TermsList {
+    pub fn new<T: Into<Text>, D: Into<Text>>(items: impl IntoIterator<Item = (T, D)>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|(term, description)| (term.into(), description.into()))
+                .collect(),
+        }
+    }
+}
+
+impl Block for TermsList {
+    fn kind(&self) -> BlockKind {
+        BlockKind::TermsList
+    }
+
+    fn render(&self, output: &mut String) {
+        self.render_with(output, &RenderContext::default());
+    }
+
+    fn render_with(&self, output: &mut String, context: &RenderContext) {
+        use std::fmt::Write;
+
+        let settings = context.settings();
+        for (term, description) in &self.items {
+            let term_markup = term.render_markup(settings);
+            // A bare colon would end the term early, so plain terms escape it.
+            let term_markup = if term.is_styled() {
+                term_markup
+            } else {
+                term_markup.replace(':', "\\:")
+            };
+
+            writeln!(
+                output,
+                "/ {}: {}",
+                term_markup,
+                description.render_markup(settings)
+            )
+            .expect("writing to string never fails");
+        }
+
+        output.push('\n');
+    }
+
+    fn langs(&self) -> Vec<&str> {
+        self.items
+            .iter()
+            .flat_map(|(term, description)| [term, description])
+            .filter_map(Text::lang_tag)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_colons_in_plain_terms() {
+        let mut rendered = String::new();
+        let list = TermsList::new([
+            ("Ratio: A", "First"),
+            ("API", "Application [programming] interface"),
+        ]);
+
+        list.render(&mut rendered);
+
+        assert_eq!(
+            rendered,
+            "/ Ratio\\: A: First\n/ API: Application \\[programming\\] interface\n\n"
+        );
+        assert!(crate::validate_markup(&rendered).is_ok());
+    }
+}
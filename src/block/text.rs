@@ -1,7 +1,15 @@
+use super::{lang::parse_lang, length::length};
+use crate::{
+    color::Color,
+    render::RenderSettings,
+    typst_escape::{escape_markup, escape_string},
+};
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Text {
     content: String,
     options: TextOptions,
+    preserve_whitespace: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -47,6 +55,7 @@ impl Text {
         Self {
             content: content.into(),
             options: TextOptions::default(),
+            preserve_whitespace: None,
         }
     }
 
@@ -54,9 +63,21 @@ impl Text {
         Self {
             content: content.into(),
             options,
+            preserve_whitespace: None,
         }
     }
 
+    /// Configure whether leading and trailing whitespace is kept when
+    /// rendering, overriding the report-wide default.
+    ///
+    /// Text is trimmed unless either this or
+    /// [`Report::preserve_whitespace`](crate::Report::preserve_whitespace)
+    /// opts in. Quotes and backslashes are escaped either way.
+    pub fn preserve_whitespace(mut self, preserve: bool) -> Self {
+        self.preserve_whitespace = Some(preserve);
+        self
+    }
+
     pub fn as_str(&self) -> &str {
         &self.content
     }
@@ -65,16 +86,24 @@ impl Text {
         &self.options
     }
 
-    pub fn fill<T: Into<String>>(mut self, color: T) -> Self {
-        self.options.fill = Some(TextOptionValue::raw(color));
+    pub fn fill<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.fill = Some(TextOptionValue::raw(color.into().to_string()));
         self
     }
 
+    /// Set the text language from a tag such as `"en"` or `"en-US"`.
+    ///
+    /// See [`TextOptions::lang`] for how the tag is split.
     pub fn lang<T: Into<String>>(mut self, lang: T) -> Self {
-        self.options.lang = Some(TextOptionValue::str(lang));
+        self.options = self.options.lang(lang);
         self
     }
 
+    /// Return the language tag set with [`Text::lang`], if any.
+    pub(crate) fn lang_tag(&self) -> Option<&str> {
+        self.options.lang_tag()
+    }
+
     pub fn size<T: Into<String>>(mut self, size: T) -> Self {
         self.options.size = Some(TextOptionValue::raw(size));
         self
@@ -100,6 +129,24 @@ impl Text {
         self
     }
 
+    /// Set the letter spacing in `em`, relative to the font size.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn tracking_em(mut self, tracking: f32) -> Self {
+        self.options = self.options.tracking_em(tracking);
+        self
+    }
+
+    /// Set the letter spacing in points.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn tracking_pt(mut self, tracking: f32) -> Self {
+        self.options = self.options.tracking_pt(tracking);
+        self
+    }
+
     pub fn stretch<T: Into<String>>(mut self, stretch: T) -> Self {
         self.options.stretch = Some(TextOptionValue::raw(stretch));
         self
@@ -206,12 +253,23 @@ impl Text {
     }
 
     pub fn render(&self) -> String {
+        self.render_with(&RenderSettings::default())
+    }
+
+    /// Render the text, falling back to document-level settings for anything
+    /// the text does not configure itself.
+    ///
+    /// # Arguments
+    /// - `settings`: Document-level defaults configured on the report.
+    pub(crate) fn render_with(&self, settings: &RenderSettings) -> String {
+        let content = self.content_with(settings);
+
         if self.options.is_empty() {
-            return self.content.trim().to_string();
+            return content.to_string();
         }
 
         let mut rendered = String::from("#text(");
-        rendered.push_str(&format!("\"{}\"", escape_str(self.content.trim())));
+        rendered.push_str(&format!("\"{}\"", escape_string(content)));
 
         for option in self.options.iter() {
             rendered.push_str(", ");
@@ -221,23 +279,61 @@ impl Text {
         rendered.push(')');
         rendered
     }
+
+    /// Keep surrounding whitespace unless the caller already chose a
+    /// whitespace behavior explicitly.
+    pub(crate) fn preserve_whitespace_by_default(mut self) -> Self {
+        self.preserve_whitespace.get_or_insert(true);
+        self
+    }
+
+    /// Return whether the text carries styling and renders as a `#text(...)`
+    /// call.
+    pub(crate) fn is_styled(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// Render the text for use directly inside Typst markup, such as a list
+    /// item.
+    ///
+    /// Unstyled text is escaped so markup metacharacters render literally,
+    /// while styled text is emitted as a `#text(...)` call via [`Text::render`].
+    ///
+    /// # Arguments
+    /// - `settings`: Document-level defaults configured on the report.
+    pub(crate) fn render_markup(&self, settings: &RenderSettings) -> String {
+        if self.options.is_empty() {
+            escape_markup(self.content_with(settings))
+        } else {
+            self.render_with(settings)
+        }
+    }
+
+    /// Return the content, trimmed unless whitespace should be preserved.
+    ///
+    /// # Arguments
+    /// - `settings`: Document-level defaults configured on the report.
+    fn content_with(&self, settings: &RenderSettings) -> &str {
+        if self
+            .preserve_whitespace
+            .unwrap_or(settings.preserve_whitespace())
+        {
+            &self.content
+        } else {
+            self.content.trim()
+        }
+    }
 }
 
 impl From<String> for Text {
     fn from(value: String) -> Self {
-        Self {
-            content: value,
-            options: TextOptions::default(),
-        }
+        Self::new(value)
     }
 }
 
 impl From<&str> for Text {
     fn from(value: &str) -> Self {
-        Self {
-            content: value.to_string(),
-            options: TextOptions::default(),
-        }
+        Self::new(value)
     }
 }
 
@@ -250,16 +346,32 @@ impl std::ops::Deref for Text {
 }
 
 impl TextOptions {
-    pub fn fill<T: Into<String>>(mut self, color: T) -> Self {
-        self.fill = Some(TextOptionValue::raw(color));
+    pub fn fill<C: Into<Color>>(mut self, color: C) -> Self {
+        self.fill = Some(TextOptionValue::raw(color.into().to_string()));
         self
     }
 
+    /// Set the text language from a tag such as `"en"`, `"en-US"` or
+    /// `"zh-Hant-TW"`.
+    ///
+    /// Script and region subtags are emitted as Typst's separate `script` and
+    /// `region` arguments; an explicit [`TextOptions::region`] wins over the
+    /// tag's region. [`Report::render_validated`](crate::Report::render_validated)
+    /// rejects tags that are not a plausible language code, such as
+    /// `"english"`.
     pub fn lang<T: Into<String>>(mut self, lang: T) -> Self {
         self.lang = Some(TextOptionValue::str(lang));
         self
     }
 
+    /// Return the language tag set with [`TextOptions::lang`], if any.
+    pub(crate) fn lang_tag(&self) -> Option<&str> {
+        match &self.lang {
+            Some(TextOptionValue::Str(tag) | TextOptionValue::Raw(tag)) => Some(tag),
+            None => None,
+        }
+    }
+
     pub fn size<T: Into<String>>(mut self, size: T) -> Self {
         self.size = Some(TextOptionValue::raw(size));
         self
@@ -285,6 +397,22 @@ impl TextOptions {
         self
     }
 
+    /// Set the letter spacing in `em`, relative to the font size.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn tracking_em(self, tracking: f32) -> Self {
+        self.tracking(length(tracking, "em"))
+    }
+
+    /// Set the letter spacing in points.
+    ///
+    /// # Panics
+    /// Panics if the value is not finite.
+    pub fn tracking_pt(self, tracking: f32) -> Self {
+        self.tracking(length(tracking, "pt"))
+    }
+
     pub fn stretch<T: Into<String>>(mut self, stretch: T) -> Self {
         self.stretch = Some(TextOptionValue::raw(stretch));
         self
@@ -390,43 +518,132 @@ impl TextOptions {
         self
     }
 
+    /// Combine two option sets, with every option set in `other` overriding
+    /// the same option in `self`.
+    ///
+    /// This supports a house style with per-block tweaks, such as
+    /// `house_style.clone().merge(TextOptions::default().weight("bold"))`.
+    pub fn merge(self, other: TextOptions) -> TextOptions {
+        let TextOptions {
+            fill,
+            lang,
+            size,
+            font,
+            style,
+            weight,
+            tracking,
+            stretch,
+            variant,
+            baseline,
+            underline,
+            overline,
+            line_through,
+            outline,
+            shadow,
+            offset,
+            rotate,
+            scale,
+            dir,
+            writing_mode,
+            region,
+            justification,
+            align,
+            first_line_indent,
+            hanging_indent,
+            leading,
+            spacing,
+            parbreak,
+        } = other;
+
+        TextOptions {
+            fill: fill.or(self.fill),
+            lang: lang.or(self.lang),
+            size: size.or(self.size),
+            font: font.or(self.font),
+            style: style.or(self.style),
+            weight: weight.or(self.weight),
+            tracking: tracking.or(self.tracking),
+            stretch: stretch.or(self.stretch),
+            variant: variant.or(self.variant),
+            baseline: baseline.or(self.baseline),
+            underline: underline.or(self.underline),
+            overline: overline.or(self.overline),
+            line_through: line_through.or(self.line_through),
+            outline: outline.or(self.outline),
+            shadow: shadow.or(self.shadow),
+            offset: offset.or(self.offset),
+            rotate: rotate.or(self.rotate),
+            scale: scale.or(self.scale),
+            dir: dir.or(self.dir),
+            writing_mode: writing_mode.or(self.writing_mode),
+            region: region.or(self.region),
+            justification: justification.or(self.justification),
+            align: align.or(self.align),
+            first_line_indent: first_line_indent.or(self.first_line_indent),
+            hanging_indent: hanging_indent.or(self.hanging_indent),
+            leading: leading.or(self.leading),
+            spacing: spacing.or(self.spacing),
+            parbreak: parbreak.or(self.parbreak),
+        }
+    }
+
     fn is_empty(&self) -> bool {
         self.iter().next().is_none()
     }
 
     fn iter(&self) -> impl Iterator<Item = String> + '_ {
-        [
-            ("fill", &self.fill),
-            ("lang", &self.lang),
-            ("size", &self.size),
-            ("font", &self.font),
-            ("style", &self.style),
-            ("weight", &self.weight),
-            ("tracking", &self.tracking),
-            ("stretch", &self.stretch),
-            ("variant", &self.variant),
-            ("baseline", &self.baseline),
-            ("underline", &self.underline),
-            ("overline", &self.overline),
-            ("line_through", &self.line_through),
-            ("outline", &self.outline),
-            ("shadow", &self.shadow),
-            ("offset", &self.offset),
-            ("rotate", &self.rotate),
-            ("scale", &self.scale),
-            ("dir", &self.dir),
-            ("writing_mode", &self.writing_mode),
-            ("region", &self.region),
-            ("justification", &self.justification),
-            ("align", &self.align),
-            ("first_line_indent", &self.first_line_indent),
-            ("hanging_indent", &self.hanging_indent),
-            ("leading", &self.leading),
-            ("spacing", &self.spacing),
-            ("parbreak", &self.parbreak),
-        ]
-        .into_iter()
-        .filter_map(|(name, value)| value.as_ref().map(|v| format!("{}: {}", name, v)))
+        let fill = self.fill.iter().map(|fill| format!("fill: {}", fill));
+
+        fill.chain(self.lang_arguments()).chain(
+            [
+                ("size", &self.size),
+                ("font", &self.font),
+                ("style", &self.style),
+                ("weight", &self.weight),
+                ("tracking", &self.tracking),
+                ("stretch", &self.stretch),
+                ("variant", &self.variant),
+                ("baseline", &self.baseline),
+                ("underline", &self.underline),
+                ("overline", &self.overline),
+                ("line_through", &self.line_through),
+                ("outline", &self.outline),
+                ("shadow", &self.shadow),
+                ("offset", &self.offset),
+                ("rotate", &self.rotate),
+                ("scale", &self.scale),
+                ("dir", &self.dir),
+                ("writing_mode", &self.writing_mode),
+                ("region", &self.region),
+                ("justification", &self.justification),
+                ("align", &self.align),
+                ("first_line_indent", &self.first_line_indent),
+                ("hanging_indent", &self.hanging_indent),
+                ("leading", &self.leading),
+                ("spacing", &self.spacing),
+                ("parbreak", &self.parbreak),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.as_ref().map(|v| format!("{}: {}", name, v))),
+        )
+    }
+
+    /// Split the language tag into `lang`, `script` and `region` arguments,
+    /// leaving the region to an explicitly configured one.
+    fn lang_arguments(&self) -> Vec<String> {
+        let Some(tag) = self.lang_tag() else {
+            return Vec::new();
+        };
+
+        match parse_lang(tag) {
+            Some(mut parsed) => {
+                if self.region.is_some() {
+                    parsed.region = None;
+                }
+                parsed.arguments()
+            }
+            None => vec![format!("lang: {}", TextOptionValue::str(tag))],
+        }
     }
 }
 
@@ -444,19 +661,46 @@ impl std::fmt::Display for TextOptionValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TextOptionValue::Raw(value) => write!(f, "{}", value),
-            TextOptionValue::Str(value) => write!(f, "\"{}\"", escape_str(value)),
+            TextOptionValue::Str(value) => write!(f, "\"{}\"", escape_string(value)),
         }
     }
 }
 
-pub(crate) fn escape_str(value: &str) -> String {
-    value.replace('\\', "\\\\").replace('"', "\\\"")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn splits_language_tag_into_lang_and_region() {
+        let rendered = Text::new("Color").lang("en-US").render();
+
+        assert_eq!(rendered, "#text(\"Color\", lang: \"en\", region: \"US\")");
+    }
+
+    #[test]
+    fn explicit_region_wins_over_language_tag() {
+        let region_first = Text::new("Colour").region("\"GB\"").lang("en-US");
+        let region_last = Text::new("Colour").lang("en-US").region("\"GB\"");
+
+        assert_eq!(region_first, region_last);
+        assert_eq!(
+            region_first.render(),
+            "#text(\"Colour\", lang: \"en\", region: \"GB\")"
+        );
+    }
+
+    #[test]
+    fn typed_tracking_adds_unit_suffix() {
+        assert_eq!(
+            Text::new("Spaced").tracking_em(0.1).render(),
+            "#text(\"Spaced\", tracking: 0.1em)"
+        );
+        assert_eq!(
+            Text::new("Tight").tracking_pt(-0.5).render(),
+            "#text(\"Tight\", tracking: -0.5pt)"
+        );
+    }
+
     #[test]
     fn renders_all_text_options_with_text_options_builder() {
         let options = TextOptions::default()
@@ -497,6 +741,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_overrides_only_options_set_in_other() {
+        let house_style = TextOptions::default().size("11pt").fill("navy");
+        let tweak = TextOptions::default().weight("bold").fill("red");
+
+        let rendered = Text::with_options("Merged", house_style.merge(tweak)).render();
+
+        assert_eq!(
+            rendered,
+            "#text(\"Merged\", fill: red, size: 11pt, weight: \"bold\")"
+        );
+    }
+
+    #[test]
+    fn preserves_whitespace_when_requested() {
+        let plain = Text::new("  indented  ").preserve_whitespace(true);
+        let styled = Text::new("  padded \"quote\"  ")
+            .fill("red")
+            .preserve_whitespace(true);
+
+        let plain_rendered = plain.render();
+        let styled_rendered = styled.render();
+
+        assert_eq!(plain_rendered, "  indented  ");
+        assert_eq!(
+            styled_rendered,
+            "#text(\"  padded \\\"quote\\\"  \", fill: red)"
+        );
+    }
+
+    #[test]
+    fn trims_by_default_and_follows_document_setting() {
+        let text = Text::new("  spaced  ");
+        let preserving = RenderSettings::default().with_preserve_whitespace(true);
+
+        let trimmed = text.render();
+        let preserved = text.render_with(&preserving);
+        let overridden = text
+            .clone()
+            .preserve_whitespace(false)
+            .render_with(&preserving);
+
+        assert_eq!(trimmed, "spaced");
+        assert_eq!(preserved, "  spaced  ");
+        assert_eq!(overridden, "spaced");
+    }
+
     #[test]
     fn renders_all_text_options_with_text_builder_methods() {
         let rendered = Text::new("Builder syntax")
@@ -0,0 +1,117 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use zip::{ZipWriter, write::SimpleFileOptions};
+
+use crate::{
+    output::OutputFormat,
+    report::{RenderError, Report, is_remote_image},
+    typst_escape::escape_string,
+};
+
+/// Folder inside a bundle that holds the report's image files.
+const ASSET_DIR: &str = "assets";
+
+impl Report {
+    /// Write a ZIP archive holding everything needed to share the report: the
+    /// Typst source, the PDF when [`Report::generate_pdf`] is enabled, the
+    /// inline bibliography, and every local image the report references.
+    ///
+    /// Images are stored under `assets/` and the bundled source points at
+    /// those copies, so the extracted archive compiles on its own. Remote
+    /// images and image files that do not exist are left untouched.
+    ///
+    /// # Errors
+    /// Returns an error when the markup fails validation, the PDF fails to
+    /// compile, or an image or the archive cannot be read or written.
+    pub fn write_bundle<P: Into<PathBuf>>(&self, path: P) -> io::Result<()> {
+        let mut source = self
+            .render_validated()
+            .map_err(|errors| io::Error::other(RenderError::InvalidMarkup(errors)))?;
+        let assets = self.bundle_assets()?;
+
+        for (original, (bundle_path, _)) in &assets {
+            source = source.replace(
+                &format!("image(\"{}\"", escape_string(original)),
+                &format!("image(\"{}\"", escape_string(bundle_path)),
+            );
+        }
+
+        let typst_name = self.output_path(OutputFormat::Typst);
+        let mut archive = ZipWriter::new(fs::File::create(path.into())?);
+        let options = SimpleFileOptions::default();
+
+        archive.start_file(typst_name.to_string_lossy(), options)?;
+        archive.write_all(source.as_bytes())?;
+
+        if self.writes_format(OutputFormat::Pdf) {
+            let pdf = self.to_pdf_bytes().map_err(io::Error::other)?;
+            let pdf_name = typst_name.with_extension(OutputFormat::Pdf.extension());
+            archive.start_file(pdf_name.to_string_lossy(), options)?;
+            archive.write_all(&pdf)?;
+        }
+
+        if let Some(yaml) = self.inline_bibliography() {
+            archive.start_file(self.inline_bibliography_path().to_string_lossy(), options)?;
+            archive.write_all(yaml.as_bytes())?;
+        }
+
+        for (bundle_path, contents) in assets.values() {
+            archive.start_file(bundle_path.as_str(), options)?;
+            archive.write_all(contents)?;
+        }
+
+        archive.finish()?;
+        Ok(())
+    }
+
+    /// Collect the contents of every bundled image, keyed by the path the
+    /// report uses, alongside the path it gets inside the bundle.
+    ///
+    /// Images sharing a file name get a numeric prefix so neither is lost.
+    fn bundle_assets(&self) -> io::Result<BTreeMap<String, (String, Vec<u8>)>> {
+        let root = std::env::current_dir()?;
+        let mut assets = BTreeMap::new();
+        let mut taken = Vec::new();
+
+        for image in self
+            .all_blocks()
+            .into_iter()
+            .flat_map(|block| block.images())
+        {
+            let original = image.path();
+            if assets.contains_key(original) || is_remote_image(original) {
+                continue;
+            }
+
+            let contents = match image.bytes() {
+                Some(bytes) => bytes.to_vec(),
+                None => match fs::read(root.join(original.trim_start_matches('/'))) {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                },
+            };
+
+            let file_name = Path::new(original).file_name().map_or_else(
+                || "image".to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+            let mut bundle_path = format!("{ASSET_DIR}/{file_name}");
+            let mut suffix = 1;
+            while taken.contains(&bundle_path) {
+                suffix += 1;
+                bundle_path = format!("{ASSET_DIR}/{suffix}-{file_name}");
+            }
+
+            taken.push(bundle_path.clone());
+            assets.insert(original.to_string(), (bundle_path, contents));
+        }
+
+        Ok(assets)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::typst_escape::escape_string;
+
+/// A Typst color value.
+///
+/// Named variants map to Typst's predefined colors, so a misspelled color is
+/// caught by the Rust compiler instead of at Typst compile time. Strings
+/// convert to [`Color::Raw`] and are emitted unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Gray,
+    Silver,
+    White,
+    Navy,
+    Blue,
+    Aqua,
+    Teal,
+    Eastern,
+    Purple,
+    Fuchsia,
+    Maroon,
+    Red,
+    Orange,
+    Yellow,
+    Olive,
+    Green,
+    Lime,
+    /// A hex color such as `"#1f77b4"`, rendered as `rgb("#1f77b4")`.
+    Rgb(String),
+    /// A grayscale color from `0` (black) to `255` (white), rendered as
+    /// `luma(..)`.
+    Luma(u8),
+    /// Any Typst color expression, emitted as-is.
+    Raw(String),
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Color::Black => "black",
+            Color::Gray => "gray",
+            Color::Silver => "silver",
+            Color::White => "white",
+            Color::Navy => "navy",
+            Color::Blue => "blue",
+            Color::Aqua => "aqua",
+            Color::Teal => "teal",
+            Color::Eastern => "eastern",
+            Color::Purple => "purple",
+            Color::Fuchsia => "fuchsia",
+            Color::Maroon => "maroon",
+            Color::Red => "red",
+            Color::Orange => "orange",
+            Color::Yellow => "yellow",
+            Color::Olive => "olive",
+            Color::Green => "green",
+            Color::Lime => "lime",
+            Color::Rgb(hex) => return write!(f, "rgb(\"{}\")", escape_string(hex)),
+            Color::Luma(value) => return write!(f, "luma({})", value),
+            Color::Raw(value) => value,
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl From<&str> for Color {
+    fn from(value: &str) -> Self {
+        Color::Raw(value.to_string())
+    }
+}
+
+impl From<String> for Color {
+    fn from(value: String) -> Self {
+        Color::Raw(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_named_colors_as_typst_identifiers() {
+        assert_eq!(Color::Navy.to_string(), "navy");
+        assert_eq!(Color::Red.to_string(), "red");
+    }
+
+    #[test]
+    fn renders_rgb_and_luma_colors() {
+        assert_eq!(Color::Rgb("#1f77b4".into()).to_string(), "rgb(\"#1f77b4\")");
+        assert_eq!(Color::Luma(128).to_string(), "luma(128)");
+    }
+
+    #[test]
+    fn renders_raw_colors_unchanged() {
+        let color = Color::from("blue.darken(20%)");
+
+        assert_eq!(color, Color::Raw("blue.darken(20%)".into()));
+        assert_eq!(color.to_string(), "blue.darken(20%)");
+    }
+}
@@ -0,0 +1,139 @@
+use serde_json::Value;
+
+use crate::report::Report;
+
+/// Error returned when a JSON configuration cannot be turned into a
+/// [`Report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The configuration is not a JSON object.
+    NotAnObject,
+    /// The required `title` key is absent.
+    MissingTitle,
+    /// A known key holds a value of the wrong type.
+    InvalidType {
+        key: &'static str,
+        expected: &'static str,
+    },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::NotAnObject => write!(f, "report config must be a JSON object"),
+            ConfigError::MissingTitle => write!(f, "report config is missing a \"title\""),
+            ConfigError::InvalidType { key, expected } => {
+                write!(f, "report config key \"{}\" must be a {}", key, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Report {
+    /// Build a report from a JSON configuration object.
+    ///
+    /// Recognized keys are `title` (required), `author`, `header`, and
+    /// `footer` as strings, and `outline`, `contents_table`, and
+    /// `figure_table` as booleans. Unknown keys are ignored.
+    ///
+    /// # Errors
+    /// Returns a [`ConfigError`] when the value is not an object, the title is
+    /// missing, or a recognized key has the wrong type.
+    pub fn from_config(value: &Value) -> Result<Report, ConfigError> {
+        let config = value.as_object().ok_or(ConfigError::NotAnObject)?;
+        let string = |key: &'static str| match config.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value.clone())),
+            Some(_) => Err(ConfigError::InvalidType {
+                key,
+                expected: "string",
+            }),
+        };
+        let flag = |key: &'static str| match config.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::Bool(value)) => Ok(Some(*value)),
+            Some(_) => Err(ConfigError::InvalidType {
+                key,
+                expected: "boolean",
+            }),
+        };
+
+        let mut report = Report::new(string("title")?.ok_or(ConfigError::MissingTitle)?);
+
+        if let Some(author) = string("author")? {
+            report = report.author(author);
+        }
+
+        if let Some(header) = string("header")? {
+            report = report.header(header);
+        }
+
+        if let Some(footer) = string("footer")? {
+            report = report.footer(footer);
+        }
+
+        if let Some(outline) = flag("outline")? {
+            report = report.with_outline(outline);
+        }
+
+        if let Some(contents_table) = flag("contents_table")? {
+            report = report.with_contents_table(contents_table);
+        }
+
+        if let Some(figure_table) = flag("figure_table")? {
+            report = report.with_figure_table(figure_table);
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builds_report_from_config_object() {
+        let config = json!({
+            "title": "Pipeline Run",
+            "author": "CI",
+            "footer": "Internal",
+            "outline": false,
+            "contents_table": true,
+            "unknown": 42,
+        });
+
+        let rendered = Report::from_config(&config)
+            .expect("config should be valid")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("#set document(title: \"Pipeline Run\", author: \"CI\")"));
+        assert!(rendered.contains("footer: section(body: [Internal])"));
+        assert!(!rendered.contains("#outline()"));
+        assert!(rendered.contains("#contents_table()"));
+    }
+
+    #[test]
+    fn rejects_config_without_title() {
+        let result = Report::from_config(&json!({ "author": "CI" }));
+
+        assert_eq!(result.unwrap_err(), ConfigError::MissingTitle);
+    }
+
+    #[test]
+    fn rejects_config_with_wrongly_typed_key() {
+        let result = Report::from_config(&json!({ "title": "Typed", "outline": "yes" }));
+
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::InvalidType {
+                key: "outline",
+                expected: "boolean",
+            }
+        );
+    }
+}
@@ -27,44 +27,90 @@
 //! You can also compile Typst source directly to PDF with
 //! [`compile_pdf`](crate::compile_pdf), which uses the embedded Typst engine and
 //! fonts to avoid extra system dependencies.
+// The lint only fires for the `ReportCreation` crate name itself, which cannot
+// be scoped any narrower than the crate root.
+#![allow(non_snake_case)]
+
 mod block;
+#[cfg(feature = "bundle")]
+mod bundle;
+mod color;
+#[cfg(feature = "json")]
+mod config;
+mod manifest;
+mod number_format;
+mod output;
 mod render;
 mod report;
 mod section;
+mod show_rule;
+mod template;
+pub mod typst_escape;
+mod world;
 
 #[cfg(feature = "polars")]
 pub use block::from_polars_dataframe;
 pub use block::{
-    Block, BlockNode, Figure, FigureKind, Image, ImageOptions, Link, LinkDestination, Text,
-    TextOptions, bullets, code, figure, image, link_to_location, link_to_url, numbered, paragraph,
-    raw, table, text, text_with_options,
+    Attribution, Block, BlockKind, BlockNode, CellWrap, CodeBlock, ColumnBreak, Figure, FigureKind,
+    Gallery, Image, ImageOptions, IncludeBlock, Inline, Link, LinkDestination, MetadataBlock,
+    NumberedList, Quote, RichText, Spacing, SpacingAxis, TableBlock, TableBuilder, TableShapeError,
+    TermsList, Text, TextOptions, bullets, code, code_span, column_break, figure, footnote_ref,
+    gallery, hspace, image, include_file, link_to_location, link_to_url, metadata, numbered,
+    paragraph, quote, raw, raw_escaped, strike, table, terms, text, text_with_options, underline,
+    vspace,
+};
+#[cfg(feature = "qr")]
+pub use block::{QrCode, qr_code};
+pub use color::Color;
+#[cfg(feature = "json")]
+pub use config::ConfigError;
+pub use number_format::NumberFormat;
+pub use output::OutputFormat;
+pub use render::{RenderContext, RenderSettings, SpacingPolicy};
+pub use report::{
+    Outline, OutlinePosition, PageSection, RenderError, Report, compile_pdf,
+    compile_pdf_with_timeout, validate_markup,
 };
-pub use report::{Outline, PageSection, Report, compile_pdf};
 pub use section::Section;
+pub use show_rule::ShowRule;
+pub use template::ReportTemplate;
+pub use world::ReportWorld;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::{
         env, fs,
-        path::PathBuf,
+        path::{Path, PathBuf},
+        sync::{Mutex, MutexGuard},
         time::{SystemTime, UNIX_EPOCH},
     };
 
+    /// Serializes tests that change the process-wide working directory.
+    static CWD_LOCK: Mutex<()> = Mutex::new(());
+
     struct DirGuard {
         original: PathBuf,
         temp_dir: PathBuf,
+        _lock: MutexGuard<'static, ()>,
     }
 
     impl DirGuard {
         fn in_temp(test_name: &str) -> Self {
+            let lock = CWD_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
             let temp_dir = unique_temp_dir(test_name);
             fs::create_dir_all(&temp_dir).expect("should be able to create temp dir");
 
             let original = env::current_dir().expect("cwd should be available");
             env::set_current_dir(&temp_dir).expect("should be able to set cwd for test");
 
-            Self { original, temp_dir }
+            Self {
+                original,
+                temp_dir,
+                _lock: lock,
+            }
         }
     }
 
@@ -118,6 +164,43 @@ mod tests {
         assert!(rendered.contains("#table"));
     }
 
+    #[test]
+    fn writes_manifest_listing_outputs_with_sizes() {
+        let _guard = DirGuard::in_temp("writes_manifest_listing_outputs_with_sizes");
+        let image = Image::from_bytes(red_dot_png());
+        let image_path = image.path().to_string();
+
+        Report::new("Manifest Report")
+            .add_section(Section::new("Chart").add_block(image.into()))
+            .generate_pdf(true)
+            .write_manifest(true)
+            .render();
+
+        let typ_size = fs::metadata("manifest_report.typ")
+            .expect("typst should be written")
+            .len();
+        let pdf_size = fs::metadata("manifest_report.pdf")
+            .expect("pdf should be written")
+            .len();
+        let manifest = fs::read_to_string("manifest_report.manifest.json")
+            .expect("manifest should be written");
+
+        assert!(manifest.contains("\"title\": \"Manifest Report\""));
+        assert!(manifest.contains(&format!(
+            "{{ \"format\": \"typ\", \"path\": \"manifest_report.typ\", \"bytes\": {} }}",
+            typ_size
+        )));
+        assert!(manifest.contains(&format!(
+            "{{ \"format\": \"pdf\", \"path\": \"manifest_report.pdf\", \"bytes\": {} }}",
+            pdf_size
+        )));
+        assert!(manifest.contains(&format!(
+            "{{ \"format\": \"png\", \"path\": \"{}\", \"bytes\": {} }}",
+            image_path,
+            red_dot_png().len()
+        )));
+    }
+
     #[test]
     fn sets_page_headers_and_footers() {
         let _guard = DirGuard::in_temp("sets_page_headers_and_footers");
@@ -153,6 +236,45 @@ mod tests {
         assert!(rendered.contains("footer: section(body: [Confidential])"));
     }
 
+    #[test]
+    fn renders_page_counter_footer_that_compiles() {
+        let rendered = Report::new("Counted")
+            .footer(PageSection::page_counter())
+            .add_section(Section::new("Body").add_block(paragraph("Content.")))
+            .render_validated()
+            .expect("markup should be valid");
+
+        let pdf_bytes = compile_pdf(&rendered, std::path::Path::new("counted.typ"));
+
+        assert!(rendered.contains(
+            "footer: section(body: [#context [#counter(page).display() / #counter(page).final().first()]])"
+        ));
+        assert!(!pdf_bytes.is_empty());
+    }
+
+    #[test]
+    fn compiles_trivial_document_within_timeout() {
+        let pdf_bytes = compile_pdf_with_timeout(
+            "= Quick\n\nDone.",
+            std::path::Path::new("quick.typ"),
+            std::time::Duration::from_secs(60),
+        )
+        .expect("trivial document should compile in time");
+
+        assert!(!pdf_bytes.is_empty());
+    }
+
+    #[test]
+    fn reports_compile_failures_from_timeout_helper() {
+        let result = compile_pdf_with_timeout(
+            "#undefined_function()",
+            std::path::Path::new("broken.typ"),
+            std::time::Duration::from_secs(60),
+        );
+
+        assert!(matches!(result, Err(RenderError::Compile(_))));
+    }
+
     #[test]
     fn supports_code_block_rendering() {
         let _guard = DirGuard::in_temp("supports_code_block_rendering");
@@ -241,6 +363,1335 @@ mod tests {
         assert!(rendered.contains("#contents_table()"));
     }
 
+    #[test]
+    fn heading_offset_shifts_all_headings_down() {
+        let report = Report::new("Embedded")
+            .heading_offset(1)
+            .add_section(Section::new("Overview").add_subsection(Section::new("Details")));
+
+        let rendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.lines().any(|line| line == "== Embedded"));
+        assert!(rendered.lines().any(|line| line == "=== Overview"));
+        assert!(rendered.lines().any(|line| line == "==== Details"));
+        assert!(!rendered.lines().any(|line| line.starts_with("= ")));
+    }
+
+    #[test]
+    fn builds_report_from_section_and_front_matter_collections() {
+        let sections = vec![
+            Section::new("Alpha"),
+            Section::new("Beta"),
+            Section::new("Gamma"),
+        ];
+
+        let rendered = Report::new("Generated")
+            .add_front_matter(paragraph("First note."))
+            .with_front_matter(vec![paragraph("Second note.")])
+            .add_section(Section::new("Intro"))
+            .with_sections(sections)
+            .render_validated()
+            .expect("markup should be valid");
+
+        let positions = [
+            "First note.",
+            "Second note.",
+            "== Intro",
+            "== Alpha",
+            "== Beta",
+            "== Gamma",
+        ]
+        .iter()
+        .map(|needle| rendered.find(needle).expect("content should be rendered"))
+        .collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn renders_document_language_and_direction() {
+        let report = Report::new("Arabic")
+            .lang("ar")
+            .text_dir("rtl")
+            .add_section(Section::new("Body").add_block(paragraph(text("Override").dir("ltr"))));
+
+        let rendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.contains("#set text(lang: \"ar\", dir: rtl)"));
+        assert!(rendered.contains("#text(\"Override\", dir: ltr)"));
+    }
+
+    #[test]
+    fn renders_script_subtag_and_rejects_invalid_language() {
+        let report = Report::new("Traditional").lang("zh-Hant-TW");
+
+        assert!(
+            report
+                .render_validated()
+                .expect("markup should be valid")
+                .contains("#set text(lang: \"zh\", script: \"hant\", region: \"TW\")")
+        );
+        report.compile_in_memory().expect("script should compile");
+
+        let errors = Report::new("Invalid")
+            .lang("english")
+            .render_validated()
+            .expect_err("language should be rejected");
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0]
+                .message
+                .contains("invalid language tag: \"english\"")
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_text_language() {
+        let errors = Report::new("Invalid")
+            .add_section(
+                Section::new("Body")
+                    .lead(text("Lead").lang("deutsch"))
+                    .add_block(paragraph(text("Colour").lang("english")))
+                    .add_block(bullets([text("Item").lang("english")])),
+            )
+            .render_validated()
+            .expect_err("text language should be rejected");
+
+        let messages = errors
+            .iter()
+            .map(|err| err.message.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "invalid language tag: \"deutsch\"",
+                "invalid language tag: \"english\""
+            ]
+        );
+    }
+
+    #[test]
+    fn numeric_region_tags_compile() {
+        let report = Report::new("Latin America")
+            .lang("es-419")
+            .add_section(Section::new("Cuerpo").add_block(paragraph(text("Hola").lang("es-419"))));
+
+        let rendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.contains("#set text(lang: \"es\")"));
+        assert!(rendered.contains("#text(\"Hola\", lang: \"es\")"));
+        assert_eq!(report.page_count().expect("report should compile"), 1);
+    }
+
+    #[test]
+    fn aligns_rtl_reports_to_the_right() {
+        let list_offset = |report: Report| {
+            let document = report
+                .add_section(Section::new("Items").add_block(bullets(["First", "Second"])))
+                .compile_in_memory()
+                .expect("report should compile");
+            let page = &document.pages[0].frame;
+            let (_, position) = text_positions(page)
+                .into_iter()
+                .find(|(text, _)| text == "First")
+                .expect("list item should render");
+
+            position.x / page.width()
+        };
+
+        let explicit = list_offset(Report::new("Arabic").text_dir("rtl"));
+        let from_lang = list_offset(Report::new("Arabic").lang("ar"));
+        let ltr = list_offset(Report::new("English").text_dir("ltr"));
+
+        assert!(explicit > 0.5, "{explicit}");
+        assert!(from_lang > 0.5, "{from_lang}");
+        assert!(ltr < 0.5, "{ltr}");
+    }
+
+    #[test]
+    fn justify_emits_paragraph_rule_only_when_enabled() {
+        let render = |report: Report| report.render_validated().expect("markup should be valid");
+
+        let justified = render(Report::new("House Style").justify(true));
+        let disabled = render(Report::new("House Style").justify(false));
+        let omitted = render(Report::new("House Style"));
+
+        assert!(justified.contains("#set par(justify: true)\n"));
+        assert!(!disabled.contains("#set par"));
+        assert_eq!(disabled, omitted);
+    }
+
+    /// Concatenate the text runs of every page in layout order.
+    fn document_text(document: &typst::model::Document) -> String {
+        fn collect(frame: &typst::layout::Frame, text: &mut String) {
+            for (_, item) in frame.items() {
+                match item {
+                    typst::layout::FrameItem::Group(group) => collect(&group.frame, text),
+                    typst::layout::FrameItem::Text(run) => text.push_str(&run.text),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut text = String::new();
+        for page in &document.pages {
+            collect(&page.frame, &mut text);
+        }
+        text
+    }
+
+    /// Collect every text run in a frame with its position on the page.
+    pub(crate) fn text_positions(
+        frame: &typst::layout::Frame,
+    ) -> Vec<(String, typst::layout::Point)> {
+        fn collect(
+            frame: &typst::layout::Frame,
+            offset: typst::layout::Point,
+            found: &mut Vec<(String, typst::layout::Point)>,
+        ) {
+            for (point, item) in frame.items() {
+                match item {
+                    typst::layout::FrameItem::Group(group) => {
+                        collect(&group.frame, offset + *point, found)
+                    }
+                    typst::layout::FrameItem::Text(run) => {
+                        found.push((run.text.to_string(), offset + *point))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut found = Vec::new();
+        collect(frame, typst::layout::Point::zero(), &mut found);
+        found
+    }
+
+    #[test]
+    fn repeating_header_table_compiles_across_pages() {
+        let rows = (1..=120)
+            .map(|row| vec![format!("Item {row}"), format!("{}", row * 3)])
+            .collect::<Vec<_>>();
+        let report = Report::new("Long table").add_section(
+            Section::new("Inventory").add_block(
+                TableBlock::new(["Item", "Count"], rows)
+                    .repeat_header(true)
+                    .into(),
+            ),
+        );
+
+        let pages = report.page_count().expect("report should compile");
+        let pdf = report.to_pdf_bytes().expect("report should compile");
+
+        assert!(pages > 1);
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn report_lists_used_fonts() {
+        let fonts = Report::new("Fonts")
+            .add_section(
+                Section::new("Body").add_block(paragraph(text("Mono").font("DejaVu Sans Mono"))),
+            )
+            .used_fonts()
+            .expect("report should compile");
+
+        assert!(fonts.iter().any(|family| family == "DejaVu Sans Mono"));
+        assert!(fonts.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn warn_empty_sections_lists_sections_without_content() {
+        let report = Report::new("Lint")
+            .add_part("Part I")
+            .add_section(
+                Section::new("Results")
+                    .add_block(paragraph("Filled."))
+                    .add_subsection(Section::new("Pending")),
+            )
+            .add_section(Section::new("Summary").lead("Only a lead."))
+            .add_section(
+                Section::new("Parent")
+                    .add_subsection(Section::new("Child").add_block(paragraph("Nested."))),
+            );
+
+        assert_eq!(report.warn_empty_sections(), ["Pending"]);
+        assert!(Report::new("Clean").warn_empty_sections().is_empty());
+    }
+
+    #[test]
+    fn inline_bibliography_resolves_citations_without_files() {
+        let report = Report::new("Cited")
+            .add_section(
+                Section::new("Background")
+                    .add_block(raw("Structured programming helps @dijkstra1968.\n\n"))
+                    .add_block(
+                        quote("Simplicity is prerequisite for reliability.")
+                            .attribution(Attribution::cite("dijkstra1968"))
+                            .into(),
+                    ),
+            )
+            .bibliography_inline(
+                "dijkstra1968:\n  type: Article\n  title: Go To Statement Considered Harmful\n  author: Dijkstra, Edsger W.\n  date: 1968\n",
+            );
+
+        let rendered = report.render_validated().expect("markup should be valid");
+        let pdf = report.to_pdf_bytes().expect("report should compile");
+
+        assert!(rendered.ends_with("#bibliography(\"cited.bibliography.yml\")\n\n"));
+        assert!(!std::path::Path::new("cited.bibliography.yml").exists());
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn inline_bibliography_is_listed_in_manifest_and_cleaned_up() {
+        let _guard = DirGuard::in_temp("inline_bibliography_is_listed_in_manifest");
+        let yaml = "knuth1984:\n  type: Article\n  title: Literate Programming\n  author: Knuth, Donald E.\n  date: 1984\n";
+        let report = || {
+            Report::new("Cited")
+                .add_section(Section::new("Background").add_block(raw("See @knuth1984.\n\n")))
+                .bibliography_inline(yaml)
+                .generate_pdf(true)
+                .write_manifest(true)
+        };
+
+        report().keep_typst(true).render();
+        let manifest =
+            fs::read_to_string("cited.manifest.json").expect("manifest should be written");
+        assert!(manifest.contains(&format!(
+            "{{ \"format\": \"yml\", \"path\": \"cited.bibliography.yml\", \"bytes\": {} }}",
+            yaml.len()
+        )));
+        assert!(std::path::Path::new("cited.bibliography.yml").exists());
+
+        report().keep_typst(false).render();
+        let manifest =
+            fs::read_to_string("cited.manifest.json").expect("manifest should be written");
+        assert!(!manifest.contains("bibliography.yml"));
+        assert!(!std::path::Path::new("cited.bibliography.yml").exists());
+    }
+
+    #[test]
+    fn section_into_report_renders_section_as_top_level_heading() {
+        let subsection = Section::new("Sampling")
+            .add_block(paragraph("Every tenth unit was inspected."))
+            .add_subsection(Section::new("Rejects").add_block(paragraph("Two failed.")));
+
+        let rendered = subsection
+            .into_report("Preview")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.starts_with("#set document(title: \"Preview\")\n"));
+        assert!(!rendered.contains("= Preview"));
+        assert!(rendered.contains("\n= Sampling\nEvery tenth unit was inspected.\n"));
+        assert!(rendered.contains("\n== Rejects\nTwo failed.\n"));
+    }
+
+    #[test]
+    fn annotate_source_marks_each_section_with_a_comment() {
+        let report = || {
+            Report::new("Provenance").add_section(
+                Section::new("Results")
+                    .add_block(paragraph("Body."))
+                    .add_subsection(Section::new("Split\ntitle").start_on_new_page(true)),
+            )
+        };
+
+        let plain = report().render_validated().expect("markup should be valid");
+        let annotated = report()
+            .annotate_source(true)
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(!plain.contains("// section:"));
+        assert!(annotated.contains("// section: Results\n== Results\n"));
+        assert!(annotated.contains("// section: Split title\n#pagebreak(weak: true)\n=== Split"));
+        assert_eq!(
+            report()
+                .annotate_source(true)
+                .page_count()
+                .expect("annotated report should compile"),
+            report().page_count().expect("report should compile")
+        );
+    }
+
+    #[test]
+    fn iter_sections_walks_depth_first_with_depths() {
+        let report = Report::new("Traversal")
+            .add_section(
+                Section::new("Intro")
+                    .add_subsection(
+                        Section::new("Scope").add_subsection(Section::new("Out of scope")),
+                    )
+                    .add_subsection(Section::new("Audience")),
+            )
+            .add_section(Section::part("Results").add_subsection(Section::new("Summary")));
+
+        let visited = report
+            .iter_sections()
+            .map(|(depth, section)| (depth, section.title()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            visited,
+            [
+                (0, "Intro"),
+                (1, "Scope"),
+                (2, "Out of scope"),
+                (1, "Audience"),
+                (0, "Results"),
+                (1, "Summary"),
+            ]
+        );
+        assert_eq!(visited.len(), report.section_count());
+    }
+
+    #[test]
+    fn compact_spacing_policy_leaves_one_blank_line_between_blocks() {
+        let report = || {
+            Report::new("Spacing")
+                .add_section(
+                    Section::new("Results")
+                        .add_block(paragraph("First."))
+                        .add_block(raw("#v(1em)\n\n"))
+                        .add_block(paragraph("Second."))
+                        .add_subsection(Section::new("Details").add_block(paragraph("Third."))),
+                )
+                .add_section(Section::new("Next"))
+        };
+
+        let standard = report().render_validated().expect("markup should be valid");
+        let compact = report()
+            .spacing_policy(SpacingPolicy::Compact)
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(standard.contains("#v(1em)\n\n\n\nSecond.\n\n\n=== Details\nThird.\n\n\n== Next"));
+        assert!(compact.contains(
+            "== Results\nFirst.\n\n#v(1em)\n\nSecond.\n\n=== Details\nThird.\n\n== Next"
+        ));
+        assert!(!compact.contains("\n\n\n"));
+    }
+
+    #[test]
+    fn omits_text_settings_by_default() {
+        let rendered = Report::new("Plain")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(!rendered.contains("#set text("));
+    }
+
+    #[test]
+    fn main_outline_uses_configured_outline() {
+        let rendered = Report::new("Configured")
+            .outline_config(Outline::new().indent("auto").depth(2).fill("repeat[.]"))
+            .add_section(Section::new("Body"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains(
+            "#let main_outline() = outline(\n  indent: auto,\n  depth: 2,\n  fill: repeat[.]\n)"
+        ));
+        assert!(rendered.contains("#main_outline()\n"));
+        assert!(!rendered.contains("#outline()"));
+    }
+
+    #[test]
+    fn configured_outline_is_skipped_when_outline_disabled() {
+        let rendered = Report::new("Configured")
+            .with_outline(false)
+            .outline_config(Outline::new().depth(2))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(!rendered.contains("main_outline"));
+    }
+
+    #[test]
+    fn section_spacing_emits_show_rule_for_top_level_sections() {
+        let spaced = Report::new("Spaced")
+            .section_spacing("3em")
+            .add_section(Section::new("First"))
+            .render_validated()
+            .expect("markup should be valid");
+        let default = Report::new("Default")
+            .add_section(Section::new("First"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(spaced.contains("#show heading.where(level: 2): set block(above: 3em)"));
+        assert!(!default.contains("#show heading"));
+    }
+
+    #[test]
+    fn renders_watermark_as_page_background() {
+        let rendered = Report::new("Draft")
+            .watermark("DRAFT \"v2\"")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains(
+            "#set page(background: rotate(-45deg, text(size: 72pt, fill: luma(85%), \"DRAFT \\\"v2\\\"\")))"
+        ));
+    }
+
+    #[test]
+    fn page_numbering_sets_page_number_pattern() {
+        let roman = Report::new("Preface")
+            .page_numbering("i")
+            .render_validated()
+            .expect("markup should be valid");
+        let plain = Report::new("Preface")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(roman.contains("#set page(numbering: \"i\")\n"));
+        assert!(!plain.contains("numbering: \"i\""));
+    }
+
+    #[test]
+    fn omitting_watermark_leaves_page_unchanged() {
+        let unset = Report::new("Final")
+            .render_validated()
+            .expect("markup should be valid");
+        let empty = Report::new("Final")
+            .watermark("")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(!unset.contains("background:"));
+        assert_eq!(unset, empty);
+    }
+
+    #[test]
+    fn renders_preamble_blocks_before_title_heading() {
+        let rendered = Report::new("Styled")
+            .add_preamble_block(raw("#show heading: set text(navy)"))
+            .add_front_matter(paragraph("Intro."))
+            .render_validated()
+            .expect("markup should be valid");
+
+        let document = rendered
+            .find("#set document(")
+            .expect("document settings should render");
+        let preamble = rendered
+            .find("#show heading: set text(navy)")
+            .expect("preamble block should render");
+        let title = rendered.find("= Styled").expect("title should render");
+
+        assert!(document < preamble);
+        assert!(preamble < title);
+        assert!(title < rendered.find("Intro.").expect("front matter should render"));
+    }
+
+    #[test]
+    fn rejects_sections_sharing_a_label() {
+        let validation = Report::new("Labelled")
+            .add_section(Section::new("First").label("sec:intro"))
+            .add_section(Section::new("Second").label("sec:intro"))
+            .render_validated();
+
+        let errors = validation.expect_err("duplicate labels should be rejected");
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("sec:intro"));
+    }
+
+    #[test]
+    fn renders_section_label_after_heading() {
+        let rendered = Report::new("Labelled")
+            .add_section(Section::new("Intro").label("sec:intro"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("== Intro <sec:intro>\n"));
+    }
+
+    #[test]
+    fn footnote_references_share_one_definition() {
+        let rendered = Report::new("Noted")
+            .define_footnote("audited", "Figures audited by an external firm.")
+            .add_section(
+                Section::new("Results")
+                    .add_block(paragraph(
+                        RichText::new().text("Revenue").footnote_ref("audited"),
+                    ))
+                    .add_block(paragraph(footnote_ref("audited"))),
+            )
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert_eq!(
+            rendered
+                .matches("#hide[#footnote[Figures audited by an external firm.] <audited-note>]")
+                .count(),
+            1
+        );
+        assert_eq!(rendered.matches("#footnote(<audited-note>)").count(), 2);
+    }
+
+    #[test]
+    fn collects_errors_from_every_validation_check() {
+        let errors = Report::new("Broken")
+            .check_image_paths(true)
+            .add_show_rule(ShowRule::new("table", "set text(size: ("))
+            .add_section(
+                Section::new("First")
+                    .label("dup")
+                    .add_block(image("./missing.png"))
+                    .add_block(paragraph(footnote_ref("undefined"))),
+            )
+            .add_section(Section::new("Second").label("dup"))
+            .render_validated()
+            .expect_err("every check should fail");
+
+        let messages = errors
+            .iter()
+            .map(|err| err.message.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 4, "{messages:?}");
+        assert!(messages[0].starts_with("image file not found"));
+        assert!(messages[1].starts_with("duplicate label"));
+        assert!(messages[2].starts_with("footnote reference without definition"));
+        assert!(messages[3].starts_with("invalid show rule"));
+    }
+
+    #[test]
+    fn rejects_footnote_references_without_definition() {
+        let errors = Report::new("Noted")
+            .define_footnote("audited", "Figures audited by an external firm.")
+            .add_section(
+                Section::new("Results")
+                    .add_block(paragraph(footnote_ref("audited")))
+                    .add_block(paragraph(footnote_ref("estimated")))
+                    .add_block(quote(footnote_ref("estimated")).into()),
+            )
+            .render_validated()
+            .expect_err("undefined footnote should be rejected");
+
+        let messages = errors
+            .iter()
+            .map(|err| err.message.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["footnote reference without definition: estimated"]
+        );
+    }
+
+    #[test]
+    fn escapes_markup_in_registered_footnotes() {
+        let rendered = Report::new("Noted")
+            .define_footnote("fx", "Rates in $ [USD] per *unit*.")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(
+            rendered.contains("#hide[#footnote[Rates in \\$ \\[USD\\] per \\*unit\\*.] <fx-note>]")
+        );
+    }
+
+    #[test]
+    fn hiding_title_heading_keeps_document_metadata() {
+        let rendered = Report::new("Embedded")
+            .show_title_heading(false)
+            .add_section(Section::new("Body"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("#set document(title: \"Embedded\")"));
+        assert!(!rendered.contains("= Embedded\n"));
+        assert!(rendered.contains("== Body\n"));
+    }
+
+    #[test]
+    fn renders_abstract_between_title_and_outline() {
+        let rendered = Report::new("Findings")
+            .abstract_text("We measured *everything*.")
+            .render_validated()
+            .expect("markup should be valid");
+
+        let title = rendered.find("= Findings").expect("title should render");
+        let heading = rendered
+            .find("*Abstract*")
+            .expect("abstract heading should render");
+        let content = rendered
+            .find("We measured \\*everything\\*.")
+            .expect("abstract content should be escaped");
+        let outline = rendered.find("#outline()").expect("outline should render");
+
+        assert!(title < heading);
+        assert!(heading < content);
+        assert!(content < outline);
+    }
+
+    #[test]
+    fn omits_table_functions_when_tables_are_disabled() {
+        let rendered = Report::new("Lean")
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(!rendered.contains("#let contents_table"));
+        assert!(!rendered.contains("#let figure_table"));
+    }
+
+    #[test]
+    fn renders_section_lead_before_regular_blocks() {
+        let rendered = Report::new("Led")
+            .add_section(
+                Section::new("Results")
+                    .add_block(paragraph("Details follow."))
+                    .lead("Revenue rose 12% [unaudited]."),
+            )
+            .render_validated()
+            .expect("markup should be valid");
+
+        let heading = rendered.find("== Results").expect("heading should render");
+        let lead = rendered
+            .find("#emph[Revenue rose 12% \\[unaudited\\].]")
+            .expect("lead should render escaped");
+        let block = rendered
+            .find("Details follow.")
+            .expect("block should render");
+
+        assert!(heading < lead);
+        assert!(lead < block);
+    }
+
+    #[test]
+    fn start_on_new_page_breaks_before_flagged_sections_only() {
+        let rendered = Report::new("Paged")
+            .add_section(
+                Section::new("Appendix")
+                    .start_on_new_page(true)
+                    .add_subsection(Section::new("Raw Data")),
+            )
+            .add_section(Section::new("Notes"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("#pagebreak(weak: true)\n== Appendix\n"));
+        assert!(!rendered.contains("#pagebreak(weak: true)\n=== Raw Data"));
+        assert!(!rendered.contains("#pagebreak(weak: true)\n== Notes"));
+    }
+
+    #[test]
+    fn glossary_renders_sorted_terms_after_figure_table() {
+        let entries = [
+            ("SLA", "Service level agreement"),
+            ("api", "Application programming interface"),
+            ("KPI", "Key performance indicator"),
+        ];
+        let sorted = Report::new("Ops Review")
+            .with_figure_table(true)
+            .glossary(entries)
+            .add_section(Section::new("Summary"))
+            .render_validated()
+            .expect("markup should be valid");
+        let unsorted = Report::new("Ops Review")
+            .glossary(entries)
+            .sort_glossary(false)
+            .render_validated()
+            .expect("markup should be valid");
+
+        let figures = sorted.find("= Table of Figures").expect("figure table");
+        let glossary = sorted.find("= Glossary\n").expect("glossary heading");
+        let section = sorted.find("== Summary").expect("section heading");
+        assert!(figures < glossary && glossary < section);
+        assert!(sorted.contains(
+            "/ api: Application programming interface\n\
+             / KPI: Key performance indicator\n\
+             / SLA: Service level agreement\n"
+        ));
+        assert!(unsorted.contains("/ SLA: Service level agreement\n/ api:"));
+    }
+
+    #[test]
+    fn link_style_emits_show_rule() {
+        let report = || {
+            Report::new("Linked").add_section(
+                Section::new("Links")
+                    .label("links")
+                    .add_block(link_to_url("https://example.com", "Example"))
+                    .add_block(link_to_location("<links>", "Back to top")),
+            )
+        };
+        let styled = report().link_style(Color::Blue, true);
+
+        let styled_markup = styled.render_validated().expect("markup should be valid");
+        let plain_markup = report().render_validated().expect("markup should be valid");
+
+        assert!(styled_markup.contains("#show link: it => underline(text(fill: blue, it))\n"));
+        assert!(styled_markup.contains("#link(\"https://example.com\")[Example]"));
+        assert!(!plain_markup.contains("#show link:"));
+        styled
+            .compile_in_memory()
+            .expect("styled links should compile");
+        report()
+            .compile_in_memory()
+            .expect("plain links should compile");
+    }
+
+    #[test]
+    fn counts_blocks_and_sections_across_nesting() {
+        let report = Report::new("Counted")
+            .add_front_matter(paragraph("Preface"))
+            .add_preamble_block(raw("#set par(justify: true)"))
+            .add_part("Part One")
+            .add_section(
+                Section::new("Intro")
+                    .add_block(paragraph("One"))
+                    .add_block(paragraph("Two"))
+                    .add_subsection(Section::new("Details").add_block(paragraph("Three"))),
+            )
+            .add_section(Section::new("Empty"));
+
+        assert_eq!(report.block_count(), 4);
+        assert_eq!(report.section_count(), 4);
+    }
+
+    #[test]
+    fn figure_numbering_restarts_per_section() {
+        let rendered = Report::new("Chaptered")
+            .figure_numbering("1.1")
+            .add_section(
+                Section::new("Results").add_block(figure(Image::new("./chart.png")).into()),
+            )
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains(
+            "#set figure(numbering: n => numbering(\"1.1\", counter(\"report-chapter\").get().first(), n))\n"
+        ));
+        assert!(rendered.contains(
+            "#show heading.where(level: 2): it => { counter(\"report-chapter\").step(); counter(figure.where(kind: image)).update(0);"
+        ));
+    }
+
+    #[test]
+    fn figure_numbering_counts_sections_in_compiled_document() {
+        let table_figure = |caption: &str| {
+            figure(TableBlock::new(["Metric"], [["1"]]))
+                .caption(caption)
+                .into()
+        };
+        let report = Report::new("Chaptered")
+            .figure_numbering("1.1")
+            .add_section(
+                Section::new("Setup")
+                    .add_block(table_figure("Inputs"))
+                    .add_block(table_figure("Limits")),
+            )
+            .add_section(Section::new("Results").add_block(table_figure("Totals")));
+
+        let document = report.compile_in_memory().expect("report should compile");
+        let text = document_text(&document);
+
+        assert!(text.contains("1.1: Inputs"), "{text}");
+        assert!(text.contains("1.2: Limits"), "{text}");
+        assert!(text.contains("2.1: Totals"), "{text}");
+        assert!(!text.contains("0.1"), "{text}");
+    }
+
+    #[test]
+    fn styled_runs_stay_separate_from_following_punctuation() {
+        let report = Report::new("Runs").add_section(
+            Section::new("Body").add_block(paragraph(
+                RichText::new()
+                    .text(Text::new("Total").fill(Color::Red))
+                    .text("(net)")
+                    .link("https://docs.rs", "docs")
+                    .text("."),
+            )),
+        );
+
+        let document = report.compile_in_memory().expect("report should compile");
+        let text = document_text(&document);
+
+        assert!(text.contains("Total(net)docs."), "{text}");
+        assert!(!text.contains(';'), "{text}");
+    }
+
+    #[test]
+    fn horizontal_space_separates_words_on_one_line() {
+        let report = Report::new("Spaced").add_section(Section::new("Layout").add_block(
+            paragraph(RichText::new().text("Left").hspace("3cm").text("Right")),
+        ));
+
+        let document = report.compile_in_memory().expect("report should compile");
+        let found = text_positions(&document.pages[0].frame);
+
+        let left = found
+            .iter()
+            .find(|(text, _)| text == "Left")
+            .expect("left word");
+        let right = found
+            .iter()
+            .find(|(text, _)| text == "Right")
+            .expect("right word");
+        assert_eq!(left.1.y, right.1.y);
+        assert!(right.1.x - left.1.x > typst::layout::Abs::cm(3.0));
+    }
+
+    #[test]
+    fn section_body_markup_skips_heading() {
+        let section = Section::new("Callout Source")
+            .add_block(paragraph("Body text"))
+            .add_subsection(Section::new("Nested").add_block(paragraph("More text")));
+
+        let body = section.body_markup();
+
+        assert!(body.contains("Body text"));
+        assert!(body.contains("=== Nested"));
+        assert!(!body.contains("Callout Source"));
+    }
+
+    #[test]
+    fn module_output_defines_function_without_document_setup() {
+        let rendered = Report::new("Embedded")
+            .footer("Internal")
+            .as_module("body")
+            .add_section(Section::new("Findings").add_block(paragraph("All clear.")))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.starts_with("#let body() = [\n"));
+        assert!(rendered.ends_with("]\n"));
+        assert!(rendered.contains("== Findings"));
+        assert!(!rendered.contains("#set document"));
+        assert!(!rendered.contains("#set page"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Typst function name")]
+    fn rejects_invalid_module_function_name() {
+        let _ = Report::new("Embedded").as_module("report body");
+    }
+
+    #[test]
+    fn render_with_tags_skips_sections_outside_the_include_set() {
+        let report = Report::new("Audiences")
+            .add_section(Section::new("Summary"))
+            .add_section(Section::new("Roadmap").tag("public"))
+            .add_section(
+                Section::new("Incidents")
+                    .tag("internal")
+                    .add_subsection(Section::new("Postmortems")),
+            );
+
+        let public = report
+            .render_with_tags(&["public"])
+            .expect("markup should be valid");
+        let everything = report.render_validated().expect("markup should be valid");
+
+        assert!(public.contains("== Summary"));
+        assert!(public.contains("== Roadmap"));
+        assert!(!public.contains("Incidents"));
+        assert!(!public.contains("Postmortems"));
+        assert!(everything.contains("== Incidents"));
+    }
+
+    #[test]
+    fn outline_can_follow_front_matter() {
+        let rendered = Report::new("Ordered")
+            .add_front_matter(paragraph("Executive summary"))
+            .outline_position(OutlinePosition::AfterFrontMatter)
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(
+            rendered
+                .find("Executive summary")
+                .expect("front matter should render")
+                < rendered.find("#outline()").expect("outline should render")
+        );
+    }
+
+    #[test]
+    fn renders_imports_in_order_at_the_top() {
+        let rendered = Report::new("Shared Helpers")
+            .import("helpers.typ", &["callout", "badge"])
+            .import("../brand/colors.typ", &["primary"])
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.starts_with(
+            "#import \"helpers.typ\": callout, badge\n#import \"../brand/colors.typ\": primary\n"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Typst import item")]
+    fn rejects_invalid_import_items() {
+        let _ = Report::new("Shared Helpers").import("helpers.typ", &["not valid"]);
+    }
+
+    #[test]
+    fn styled_bullet_items_keep_their_text_call() {
+        let rendered = Report::new("Styled Lists")
+            .add_section(Section::new("Risks").add_block(bullets([
+                text("Latency"),
+                text("Data loss").fill(Color::Red),
+            ])))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("- Latency\n- #text(\"Data loss\", fill: red)\n"));
+    }
+
+    #[test]
+    fn heading_colors_emit_one_show_rule_per_level() {
+        let rendered = Report::new("Branded")
+            .heading_color(1, Color::Navy)
+            .heading_color(2, Color::Rgb("#0074d9".to_string()))
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains("#show heading.where(level: 1): set text(fill: navy)\n"));
+        assert!(
+            rendered.contains("#show heading.where(level: 2): set text(fill: rgb(\"#0074d9\"))\n")
+        );
+        assert!(
+            rendered
+                .find("#show heading.where(level: 1)")
+                .expect("rule should render")
+                < rendered.find("= Branded").expect("title should render")
+        );
+    }
+
+    #[test]
+    fn continued_numbered_list_picks_up_after_intervening_blocks() {
+        let report = Report::new("Steps").add_section(
+            Section::new("Setup")
+                .add_block(numbered(["Install", "Configure"]))
+                .add_block(paragraph("Take a break."))
+                .add_block(
+                    NumberedList::new(["Deploy", "Verify"])
+                        .continue_previous(true)
+                        .into(),
+                ),
+        );
+
+        let rendered = report.render_validated().expect("markup should be valid");
+        let rerendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.contains("+ Install\n+ Configure\n"));
+        assert!(rendered.contains("#enum(start: 3, [Deploy], [Verify])\n"));
+        assert_eq!(rendered, rerendered);
+    }
+
+    #[test]
+    fn renders_part_divider_on_its_own_page() {
+        let rendered = Report::new("Handbook")
+            .part_resets_numbering(true)
+            .add_section(Section::part("Part I: Basics").add_subsection(Section::new("Setup")))
+            .render_validated()
+            .expect("markup should be valid");
+
+        let divider = rendered
+            .find(
+                "#pagebreak(weak: true)\n#align(center + horizon)[#text(size: 2.5em, weight: \"bold\")[Part I: Basics]]\n#pagebreak()\n",
+            )
+            .expect("part divider should render");
+        let reset = rendered
+            .find("#counter(heading).update(0)")
+            .expect("numbering should reset after the part");
+        let section = rendered.find("=== Setup").expect("section should render");
+
+        assert!(divider < reset);
+        assert!(reset < section);
+        assert!(!rendered.contains("== Part I"));
+    }
+
+    /// Encode a small red square as PNG bytes.
+    fn red_dot_png() -> Vec<u8> {
+        OutputFormat::Png.export(
+            &ReportWorld::new(
+                "#set page(width: 8pt, height: 8pt)\n#box(width: 4pt, height: 4pt, fill: red)",
+                Path::new("dot.typ"),
+            )
+            .compile_document(),
+        )
+    }
+
+    #[test]
+    fn byte_images_compile_without_writing_files() {
+        let _guard = DirGuard::in_temp("byte_images_compile_without_writing_files");
+        let image = Image::from_bytes(red_dot_png());
+
+        let pdf = Report::new("In Memory Image")
+            .add_section(Section::new("Chart").add_block(image.into()))
+            .to_pdf_bytes()
+            .expect("report should compile");
+
+        assert!(pdf.starts_with(b"%PDF"));
+        assert_eq!(fs::read_dir(".").expect("dir should be listed").count(), 0);
+    }
+
+    #[test]
+    fn caption_counter_names_starting_with_punctuation_compile() {
+        let image = |caption: &str| {
+            Image::from_bytes(red_dot_png())
+                .caption(caption)
+                .caption_counter("(Draft)")
+                .into()
+        };
+        let report = Report::new("Drafts").add_section(
+            Section::new("Shots")
+                .add_block(image("Login"))
+                .add_block(image("Home")),
+        );
+
+        let document = report.compile_in_memory().expect("report should compile");
+        let text = document_text(&document);
+
+        assert!(text.contains("(Draft) 1: Login"), "{text}");
+        assert!(text.contains("(Draft) 2: Home"), "{text}");
+    }
+
+    #[test]
+    fn byte_images_are_written_only_next_to_kept_typst_source() {
+        let _guard = DirGuard::in_temp("byte_images_are_written_only_next_to_kept_typst_source");
+        let image = Image::from_bytes(red_dot_png());
+        let image_path = image.path().to_string();
+        let report = |keep_typst| {
+            Report::new("Chart Report")
+                .generate_pdf(true)
+                .keep_typst(keep_typst)
+                .add_section(Section::new("Chart").add_block(image.clone().into()))
+        };
+
+        report(false).render();
+        let written_without_source = Path::new(&image_path).exists();
+        report(true).render();
+
+        assert!(!written_without_source);
+        assert!(Path::new("chart_report.pdf").exists());
+        assert_eq!(
+            fs::read(&image_path).expect("kept source should have its image"),
+            red_dot_png()
+        );
+        assert!(
+            ReportWorld::new(
+                fs::read_to_string("chart_report.typ").expect("source should be kept"),
+                Path::new("chart_report.typ"),
+            )
+            .try_compile_pdf()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn counts_pages_of_compiled_report() {
+        let pages = Report::new("Paged")
+            .add_part("Part One")
+            .add_section(Section::new("Intro").add_block(paragraph("Opening words.")))
+            .add_section(Section::new("Body").add_block(paragraph("Main content.")))
+            .page_count()
+            .expect("report should compile");
+
+        assert!(pages >= 2);
+    }
+
+    #[cfg(feature = "qr")]
+    #[test]
+    fn report_with_qr_code_compiles() {
+        let report = Report::new("Printed Summary").add_section(
+            Section::new("Online Version")
+                .add_block(qr_code("https://example.com/reports/42"))
+                .add_block(
+                    QrCode::new("https://example.com/reports/42")
+                        .size("3cm")
+                        .into(),
+                ),
+        );
+
+        let rendered = report.render_validated().expect("markup should be valid");
+        assert!(rendered.contains("#image.decode(\"<?xml"));
+        assert!(report.to_pdf_bytes().is_ok_and(|bytes| !bytes.is_empty()));
+    }
+
+    #[cfg(feature = "bundle")]
+    #[test]
+    fn bundle_holds_typst_pdf_and_rewritten_image_paths() {
+        use std::io::Read;
+
+        let _guard = DirGuard::in_temp("bundle_holds_typst_pdf_and_rewritten_image_paths");
+        fs::create_dir_all("figures").expect("image dir should be created");
+        let logo = "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"10\" height=\"10\"><rect width=\"10\" height=\"10\"/></svg>";
+        fs::write("figures/logo.svg", logo).expect("image should be written");
+
+        Report::new("Shared Report")
+            .generate_pdf(true)
+            .add_section(Section::new("Brand").add_block(image("figures/logo.svg")))
+            .write_bundle("shared.zip")
+            .expect("bundle should be written");
+
+        let mut archive = zip::ZipArchive::new(fs::File::open("shared.zip").expect("zip exists"))
+            .expect("bundle should be a zip archive");
+        let mut names = archive.file_names().map(String::from).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            ["assets/logo.svg", "shared_report.pdf", "shared_report.typ"]
+        );
+
+        let mut source = String::new();
+        archive
+            .by_name("shared_report.typ")
+            .expect("typst entry")
+            .read_to_string(&mut source)
+            .expect("typst entry should be utf-8");
+        assert!(source.contains("#image(\"assets/logo.svg\")"));
+
+        let mut bundled_logo = String::new();
+        archive
+            .by_name("assets/logo.svg")
+            .expect("image entry")
+            .read_to_string(&mut bundled_logo)
+            .expect("image entry should be utf-8");
+        assert_eq!(bundled_logo, logo);
+    }
+
+    #[test]
+    fn constructors_report_their_block_kind() {
+        #[derive(Debug, Clone)]
+        struct Signature;
+
+        impl Block for Signature {
+            fn render(&self, output: &mut String) {
+                output.push_str("Signed\n\n");
+            }
+        }
+
+        let cases: Vec<(BlockNode, BlockKind)> = vec![
+            (paragraph("Text"), BlockKind::Paragraph),
+            (bullets(["One"]), BlockKind::BulletList),
+            (numbered(["One"]), BlockKind::NumberedList),
+            (terms([("API", "Interface")]), BlockKind::TermsList),
+            (table(["Name"], [["Ada"]]), BlockKind::Table),
+            (code(Some("rust"), "fn main() {}"), BlockKind::Code),
+            (raw("#pagebreak()"), BlockKind::Raw),
+            (raw_escaped("#literal"), BlockKind::Raw),
+            (include_file("appendix.typ"), BlockKind::Include),
+            (image("./plot.png"), BlockKind::Image),
+            (gallery(["./a.png"]).into(), BlockKind::Gallery),
+            (figure(Image::new("./plot.png")).into(), BlockKind::Figure),
+            (link_to_url("https://example.com", "Site"), BlockKind::Link),
+            (link_to_location("intro", "Intro"), BlockKind::Link),
+            (quote("Quoted").into(), BlockKind::Quote),
+            (vspace("1em"), BlockKind::Spacing),
+            (column_break().into(), BlockKind::ColumnBreak),
+            (metadata([("Author", "Ada")]), BlockKind::Metadata),
+        ];
+
+        for (block, expected) in cases {
+            assert_eq!(block.kind(), expected, "{:?}", block);
+        }
+
+        let custom: BlockNode = Box::new(Signature);
+        assert!(matches!(custom.kind(), BlockKind::Other(name) if name.ends_with("Signature")));
+    }
+
+    #[test]
+    fn includes_sibling_file_after_sections() {
+        let _guard = DirGuard::in_temp("includes_sibling_file_after_sections");
+        fs::write("appendix.typ", "== Appendix\nRaw measurements.\n")
+            .expect("included file should be written");
+
+        let report = Report::new("Split Report")
+            .include_file("appendix.typ")
+            .add_section(Section::new("Summary").add_block(include_file("summary \"draft\".typ")));
+        fs::write("summary \"draft\".typ", "Summary text.\n").expect("summary should be written");
+
+        let rendered = report.render_validated().expect("markup should be valid");
+        let section = rendered.find("== Summary\n#include \"summary \\\"draft\\\".typ\"\n");
+        let appendix = rendered.find("#include \"appendix.typ\"\n");
+        assert!(section.is_some() && appendix.is_some());
+        assert!(section < appendix);
+        assert!(report.page_count().is_ok());
+    }
+
+    #[test]
+    fn clear_content_keeps_settings_but_drops_sections() {
+        let mut report = Report::new("Live Dashboard")
+            .author("Ops")
+            .footer("Internal")
+            .add_front_matter(paragraph("Snapshot notes"))
+            .add_section(Section::new("Latency").add_block(paragraph("p99 is 120ms")));
+
+        report.clear_content();
+        let rendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.contains("author: \"Ops\""));
+        assert!(rendered.contains("footer: section(body: [Internal])"));
+        assert!(rendered.contains("= Live Dashboard"));
+        assert!(!rendered.contains("=="));
+        assert!(!rendered.contains("Snapshot notes"));
+        assert_eq!(report.block_count(), 0);
+    }
+
+    #[test]
+    fn template_builds_independent_reports_with_shared_settings() {
+        let template = ReportTemplate::new()
+            .author("Platform Team")
+            .header(|| "Internal".into())
+            .footer(PageSection::page_counter)
+            .heading_color(1, Color::Navy);
+
+        let weekly = template
+            .build("Weekly Status")
+            .add_section(Section::new("Highlights"))
+            .render_validated()
+            .expect("markup should be valid");
+        let monthly = template
+            .build("Monthly Status")
+            .render_validated()
+            .expect("markup should be valid");
+
+        for rendered in [&weekly, &monthly] {
+            assert!(rendered.contains("author: \"Platform Team\""));
+            assert!(rendered.contains("#show heading.where(level: 1): set text(fill: navy)"));
+            assert!(rendered.contains("header: section(body: [Internal])"));
+            assert!(rendered.contains("footer: section(body: [#context"));
+        }
+        assert!(weekly.contains("= Weekly Status"));
+        assert!(weekly.contains("== Highlights"));
+        assert!(monthly.contains("= Monthly Status"));
+        assert!(!monthly.contains("Highlights"));
+    }
+
+    #[test]
+    fn renders_show_rules_in_insertion_order() {
+        let rendered = Report::new("Ruled")
+            .add_show_rule(ShowRule::new("table", "set text(size: 9pt)"))
+            .add_show_rule(ShowRule::new("raw", "set block(fill: luma(240))"))
+            .render_validated()
+            .expect("markup should be valid");
+
+        let table_rule = rendered
+            .find("#show table: set text(size: 9pt)\n")
+            .expect("first rule should render");
+        let raw_rule = rendered
+            .find("#show raw: set block(fill: luma(240))\n")
+            .expect("second rule should render");
+
+        assert!(table_rule < raw_rule);
+        assert!(raw_rule < rendered.find("= Ruled").expect("title should render"));
+    }
+
+    #[test]
+    fn rejects_show_rules_that_do_not_parse() {
+        let validation = Report::new("Ruled")
+            .add_show_rule(ShowRule::new("table", "set text(size: 9pt"))
+            .render_validated();
+
+        let errors = validation.expect_err("invalid rule should be rejected");
+
+        assert!(errors[0].message.contains("invalid show rule"));
+    }
+
     #[test]
     fn renders_configurable_outline_function() {
         let outline = Outline::new()
@@ -258,6 +1709,48 @@ mod tests {
         assert!(rendered.contains("  depth: 3"));
     }
 
+    #[test]
+    fn figure_list_can_target_a_single_figure_kind() {
+        let rendered = Outline::figures_of_kind(FigureKind::Image).render_function("image_list");
+
+        assert!(rendered.contains("  target: figure.where(kind: image)"));
+        assert!(!rendered.contains("  target: figure,"));
+    }
+
+    #[test]
+    fn outline_entry_style_only_applies_inside_its_outline() {
+        let rendered = Report::new("Styled Outline")
+            .outline_config(Outline::new().entry_style(1, "strong"))
+            .with_figure_table(true)
+            .render_validated()
+            .expect("markup should be valid");
+
+        assert!(rendered.contains(
+            "#let main_outline() = {\n  show outline.entry.where(level: 1): strong\n  outline()\n}\n"
+        ));
+        assert!(!rendered.contains("#show outline.entry"));
+
+        Report::new("Styled Outline")
+            .outline_config(Outline::new().entry_style(1, "strong"))
+            .add_section(Section::new("Intro"))
+            .compile_in_memory()
+            .expect("scoped show rule should compile");
+    }
+
+    #[test]
+    fn validates_standalone_markup() {
+        let valid = validate_markup("#box(stroke: 1pt)[*Balanced* markup]");
+        let invalid = validate_markup("[#unclosed(");
+
+        assert!(valid.is_ok());
+        assert!(
+            invalid
+                .unwrap_err()
+                .iter()
+                .any(|err| err.message.contains("unclosed"))
+        );
+    }
+
     #[test]
     fn validated_render_surfaces_syntax_errors() {
         let invalid_report =
@@ -274,6 +1767,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn image_path_check_reports_missing_files() {
+        let _guard = DirGuard::in_temp("image_path_check_reports_missing_files");
+
+        let report = Report::new("Images")
+            .check_image_paths(true)
+            .add_section(Section::new("Gallery").add_block(image("./missing.png")));
+
+        let validation = report.render_validated();
+
+        assert!(
+            validation
+                .unwrap_err()
+                .iter()
+                .any(|err| err.message.contains("./missing.png"))
+        );
+    }
+
+    #[test]
+    fn image_path_check_accepts_existing_and_remote_images() {
+        let _guard = DirGuard::in_temp("image_path_check_accepts_existing_and_remote_images");
+        fs::write("present.png", b"not really a png").expect("fixture should be written");
+
+        let report = Report::new("Images").check_image_paths(true).add_section(
+            Section::new("Gallery")
+                .add_block(image("./present.png"))
+                .add_block(image("https://example.com/remote.png")),
+        );
+
+        assert!(report.render_validated().is_ok());
+    }
+
     #[test]
     fn render_writes_typ_file_using_title() {
         let _guard = DirGuard::in_temp("render_writes_typ_file_using_title");
@@ -308,6 +1833,95 @@ mod tests {
         assert!(!pdf_bytes.is_empty());
     }
 
+    #[test]
+    fn render_writes_pdf_to_configured_path() {
+        let _guard = DirGuard::in_temp("render_writes_pdf_to_configured_path");
+        let cwd = env::current_dir().expect("should have temp cwd");
+        let pdf_dir = cwd.join("exports");
+        fs::create_dir_all(&pdf_dir).expect("should create export dir");
+
+        Report::new("Routed PDF")
+            .generate_pdf(true)
+            .pdf_output(pdf_dir.join("custom.pdf"))
+            .add_section(Section::new("Summary").add_block(paragraph("Elsewhere.")))
+            .render();
+
+        assert!(cwd.join("routed_pdf.typ").exists());
+        assert!(!cwd.join("routed_pdf.pdf").exists());
+        assert!(
+            !fs::read(pdf_dir.join("custom.pdf"))
+                .expect("PDF should be written")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn render_removes_typst_file_when_not_kept() {
+        let _guard = DirGuard::in_temp("render_removes_typst_file_when_not_kept");
+
+        Report::new("PDF Only")
+            .generate_pdf(true)
+            .keep_typst(false)
+            .add_section(Section::new("Summary").add_block(paragraph("Just the PDF.")))
+            .render();
+
+        let cwd = env::current_dir().expect("should have temp cwd");
+
+        assert!(cwd.join("pdf_only.pdf").exists());
+        assert!(!cwd.join("pdf_only.typ").exists());
+    }
+
+    #[test]
+    fn to_pdf_bytes_compiles_without_writing_files() {
+        let _guard = DirGuard::in_temp("to_pdf_bytes_compiles_without_writing_files");
+
+        let pdf_bytes = Report::new("In Memory")
+            .generate_pdf(true)
+            .add_section(Section::new("Summary").add_block(paragraph("No files.")))
+            .to_pdf_bytes()
+            .expect("report should compile");
+
+        let cwd = env::current_dir().expect("should have temp cwd");
+        let entries = fs::read_dir(cwd).expect("cwd should be readable").count();
+
+        assert!(pdf_bytes.starts_with(b"%PDF"));
+        assert_eq!(entries, 0);
+    }
+
+    #[test]
+    fn generate_pdf_keeps_other_configured_outputs() {
+        let charts = || Report::new("Charts").outputs([OutputFormat::Typst, OutputFormat::Svg]);
+
+        let enabled = charts().generate_pdf(true);
+        let disabled = charts().generate_pdf(true).generate_pdf(false);
+
+        for format in [OutputFormat::Typst, OutputFormat::Svg, OutputFormat::Pdf] {
+            assert!(enabled.writes_format(format), "{format:?}");
+        }
+        assert!(disabled.writes_format(OutputFormat::Svg));
+        assert!(!disabled.writes_format(OutputFormat::Pdf));
+    }
+
+    #[test]
+    fn render_writes_each_requested_output_format() {
+        let _guard = DirGuard::in_temp("render_writes_each_requested_output_format");
+
+        Report::new("Many Formats")
+            .outputs([OutputFormat::Typst, OutputFormat::Pdf])
+            .add_section(Section::new("Summary").add_block(paragraph("Both files.")))
+            .render();
+
+        let cwd = env::current_dir().expect("should have temp cwd");
+
+        assert!(cwd.join("many_formats.typ").exists());
+        assert!(
+            !fs::read(cwd.join("many_formats.pdf"))
+                .expect("PDF should be written")
+                .is_empty()
+        );
+        assert!(!cwd.join("many_formats.svg").exists());
+    }
+
     #[test]
     fn paragraphs_accept_text_objects() {
         let shared_text = text("Shared content");
@@ -324,6 +1938,55 @@ mod tests {
         assert!(rendered.match_indices(expected).count() >= 2);
     }
 
+    #[test]
+    fn dedup_front_matter_skips_consecutive_repeats() {
+        let build = |dedup| {
+            Report::new("Disclaimers")
+                .add_front_matter(paragraph("Confidential."))
+                .add_front_matter(paragraph("Confidential."))
+                .add_front_matter(paragraph("Draft."))
+                .add_front_matter(paragraph("Confidential."))
+                .dedup_front_matter(dedup)
+                .render_validated()
+                .expect("markup should be valid")
+        };
+
+        let deduped = build(true);
+        let kept = build(false);
+
+        assert!(deduped.contains("Confidential.\n\nDraft.\n\nConfidential.\n\n"));
+        assert_eq!(deduped.matches("Confidential.").count(), 2);
+        assert_eq!(kept.matches("Confidential.").count(), 3);
+    }
+
+    #[test]
+    fn sanitize_control_chars_strips_nul_but_keeps_tabs() {
+        let build = |sanitize| {
+            Report::new("Imported\u{0}Data")
+                .sanitize_control_chars(sanitize)
+                .add_section(
+                    Section::new("Rows")
+                        .add_block(paragraph("Tab\tstays, NUL\u{0} and VT\u{b} go"))
+                        .add_block(bullets(["item\u{0}"]))
+                        .add_block(table(["Cell"], [["value\u{7f}"]])),
+                )
+                .render_validated()
+                .expect("markup should be valid")
+        };
+
+        let sanitized = build(true);
+        let raw = build(false);
+
+        assert!(!sanitized.contains('\u{0}'));
+        assert!(!sanitized.contains('\u{b}'));
+        assert!(!sanitized.contains('\u{7f}'));
+        assert!(sanitized.contains("= ImportedData\n"));
+        assert!(sanitized.contains("Tab\tstays, NUL and VT go"));
+        assert!(sanitized.contains("- item\n"));
+        assert!(sanitized.contains("[value]"));
+        assert!(raw.contains('\u{0}'));
+    }
+
     #[test]
     fn renders_formatted_text() {
         let styled = text("Look at me!")
@@ -360,6 +2023,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn report_can_preserve_text_whitespace_by_default() {
+        let report = Report::new("Spacing")
+            .preserve_whitespace(true)
+            .add_section(Section::new("Body").add_block(paragraph(text("  kept  ").fill("gray"))));
+
+        let rendered = report.render_validated().expect("markup should be valid");
+
+        assert!(rendered.contains("#text(\"  kept  \", fill: gray)"));
+    }
+
     #[test]
     fn mixes_lists_tables_links_and_code() {
         let _guard = DirGuard::in_temp("mixes_lists_tables_links_and_code");
@@ -386,8 +2060,8 @@ mod tests {
         assert!(rendered.contains("+ First numbered"));
         assert!(rendered.contains("#table"));
         assert!(rendered.contains("```typst"));
-        assert!(rendered.contains("#link(target: \"https://example.com\")[Example link]"));
-        assert!(rendered.contains("#link(location: combo_location)[Jump to combo]"));
+        assert!(rendered.contains("#link(\"https://example.com\")[Example link]"));
+        assert!(rendered.contains("#link(combo_location)[Jump to combo]"));
         assert!(rendered.contains("#let combo_location = here()"));
         assert!(rendered.contains("#set text(12pt)"));
     }
@@ -444,7 +2118,7 @@ mod tests {
         assert!(rendered.contains("Front matter"));
         assert!(rendered.contains("= Overview"));
         assert!(rendered.contains("+ Step 1"));
-        assert!(rendered.contains("#link(target: \"https://docs.example.com\")[Docs]"));
+        assert!(rendered.contains("#link(\"https://docs.example.com\")[Docs]"));
         assert!(rendered.contains(
             "#figure(image(\"./diagram.svg\", width: 80%), caption: [Everything diagram], kind: image)",
         ));
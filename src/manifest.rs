@@ -0,0 +1,80 @@
+use std::{fmt::Write, path::PathBuf};
+
+/// Build the JSON manifest describing the files a render produced.
+///
+/// Keys and entries are written in a fixed order so the same outputs always
+/// produce the same manifest.
+///
+/// # Arguments
+/// - `title`: Report title recorded in the manifest.
+/// - `outputs`: File extension, path, and byte size of every file written, in
+///   the order they were produced.
+pub(crate) fn manifest_json(title: &str, outputs: &[(&str, PathBuf, usize)]) -> String {
+    let mut json = String::new();
+    writeln!(
+        json,
+        "{{\n  \"title\": {},\n  \"outputs\": [",
+        json_string(title)
+    )
+    .expect("writing to string never fails");
+
+    for (index, (format, path, bytes)) in outputs.iter().enumerate() {
+        let separator = if index + 1 < outputs.len() { "," } else { "" };
+        writeln!(
+            json,
+            "    {{ \"format\": {}, \"path\": {}, \"bytes\": {} }}{}",
+            json_string(format),
+            json_string(&path.display().to_string()),
+            bytes,
+            separator
+        )
+        .expect("writing to string never fails");
+    }
+
+    json.push_str("  ]\n}\n");
+    json
+}
+
+/// Quote and escape a value as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if ch.is_control() => {
+                write!(quoted, "\\u{:04x}", ch as u32).expect("writing to string never fails")
+            }
+            ch => quoted.push(ch),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_outputs_in_order_with_sizes() {
+        let json = manifest_json(
+            "Q3 \"Final\"",
+            &[
+                ("typ", PathBuf::from("q3_final.typ"), 120),
+                ("pdf", PathBuf::from("q3_final.pdf"), 4096),
+            ],
+        );
+
+        assert_eq!(
+            json,
+            "{\n  \"title\": \"Q3 \\\"Final\\\"\",\n  \"outputs\": [\n    { \"format\": \"typ\", \"path\": \"q3_final.typ\", \"bytes\": 120 },\n    { \"format\": \"pdf\", \"path\": \"q3_final.pdf\", \"bytes\": 4096 }\n  ]\n}\n"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+/// Separators used when rendering numbers, such as the `1.234,5` style used
+/// in many European locales.
+///
+/// The default is US style: a `.` decimal separator and `,` between
+/// thousands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal_sep: char,
+    thousands_sep: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_sep: '.',
+            thousands_sep: Some(','),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Create a format with the given decimal and thousands separators.
+    pub fn new(decimal_sep: char, thousands_sep: char) -> Self {
+        Self {
+            decimal_sep,
+            thousands_sep: Some(thousands_sep),
+        }
+    }
+
+    /// Set the character between the integer and fractional digits.
+    pub fn decimal_sep(mut self, decimal_sep: char) -> Self {
+        self.decimal_sep = decimal_sep;
+        self
+    }
+
+    /// Set the character between groups of three integer digits, or `None`
+    /// to leave digits ungrouped.
+    pub fn thousands_sep(mut self, thousands_sep: Option<char>) -> Self {
+        self.thousands_sep = thousands_sep;
+        self
+    }
+
+    /// Format a floating-point value with the shortest digits that represent
+    /// it exactly, such as `1234.5` as `1.234,5`.
+    ///
+    /// Non-finite values render as `NaN`, `inf`, or `-inf`.
+    pub fn format_float(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let plain = value.to_string();
+        let (integer, fraction) = match plain.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (plain.as_str(), None),
+        };
+
+        let mut formatted = self.group_digits(integer);
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_sep);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Format an integer, such as `-1234567` as `-1.234.567`.
+    pub fn format_integer(&self, value: i64) -> String {
+        self.group_digits(&value.to_string())
+    }
+
+    /// Insert the thousands separator into a run of integer digits with an
+    /// optional leading minus sign.
+    fn group_digits(&self, integer: &str) -> String {
+        let (sign, digits) = match integer.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", integer),
+        };
+
+        let Some(separator) = self.thousands_sep else {
+            return integer.to_string();
+        };
+
+        let mut grouped = String::from(sign);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_with_comma_decimal_and_dot_thousands() {
+        let format = NumberFormat::new(',', '.');
+
+        assert_eq!(format.format_float(1234.5), "1.234,5");
+        assert_eq!(format.format_float(-9876543.25), "-9.876.543,25");
+        assert_eq!(format.format_integer(1_000_000), "1.000.000");
+    }
+
+    #[test]
+    fn defaults_to_us_style() {
+        let format = NumberFormat::default();
+
+        assert_eq!(format.format_float(1234.5), "1,234.5");
+        assert_eq!(format.format_float(999.0), "999");
+        assert_eq!(format.format_integer(-42), "-42");
+    }
+
+    #[test]
+    fn can_leave_digits_ungrouped() {
+        let format = NumberFormat::default().decimal_sep(',').thousands_sep(None);
+
+        assert_eq!(format.format_float(1234.5), "1234,5");
+    }
+}
@@ -0,0 +1,61 @@
+use typst::{foundations::Smart, layout::Abs, model::Document, visualize::Color};
+use typst_pdf::pdf;
+
+/// Resolution used when rasterizing documents to PNG, in pixels per point.
+const PNG_PIXELS_PER_PT: f32 = 2.0;
+
+/// A file format that [`Report::render`](crate::Report::render) can write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// The generated Typst source.
+    Typst,
+    /// A PDF compiled from the Typst source.
+    Pdf,
+    /// A single SVG with every page stacked vertically.
+    Svg,
+    /// A single PNG with every page stacked vertically.
+    Png,
+}
+
+impl OutputFormat {
+    /// Return the file extension used for this format, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Typst => "typ",
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Svg => "svg",
+            OutputFormat::Png => "png",
+        }
+    }
+
+    /// Return whether this format is produced from a compiled document rather
+    /// than the Typst source itself.
+    pub(crate) fn is_compiled(&self) -> bool {
+        !matches!(self, OutputFormat::Typst)
+    }
+
+    /// Export a compiled document to this format's bytes.
+    ///
+    /// # Arguments
+    /// - `document`: Laid-out document produced by the Typst compiler.
+    ///
+    /// # Panics
+    /// Panics when called on [`OutputFormat::Typst`], which is not produced
+    /// from a compiled document, or when PNG encoding fails.
+    pub(crate) fn export(&self, document: &Document) -> Vec<u8> {
+        match self {
+            OutputFormat::Typst => panic!("Typst output is not exported from a compiled document"),
+            OutputFormat::Pdf => pdf(document, Smart::Auto, None),
+            OutputFormat::Svg => typst_svg::svg_merged(document, Abs::zero()).into_bytes(),
+            OutputFormat::Png => typst_render::render_merged(
+                document,
+                PNG_PIXELS_PER_PT,
+                Color::WHITE,
+                Abs::zero(),
+                Color::WHITE,
+            )
+            .encode_png()
+            .unwrap_or_else(|err| panic!("failed to encode PNG output: {}", err)),
+        }
+    }
+}
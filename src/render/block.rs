@@ -1,11 +1,37 @@
-use crate::block::BlockNode;
+use crate::{
+    block::{Block, BlockNode},
+    render::{RenderContext, SpacingPolicy},
+};
 
-pub(crate) fn render_blocks(output: &mut String, blocks: &[BlockNode], depth: usize) {
+pub(crate) fn render_blocks(
+    output: &mut String,
+    blocks: &[BlockNode],
+    depth: usize,
+    context: &RenderContext,
+) {
     for block in blocks {
-        block.render(output);
+        render_block(output, block.as_ref(), context);
     }
 
-    if depth > 0 {
+    if depth > 0 && context.spacing_policy() == SpacingPolicy::Standard {
         output.push('\n');
     }
 }
+
+/// Render one block, following it with exactly one blank line under
+/// [`SpacingPolicy::Compact`].
+pub(crate) fn render_block(output: &mut String, block: &dyn Block, context: &RenderContext) {
+    match context.spacing_policy() {
+        SpacingPolicy::Standard => block.render_with(output, context),
+        SpacingPolicy::Compact => {
+            let mut rendered = String::new();
+            block.render_with(&mut rendered, context);
+
+            let rendered = rendered.trim_end_matches('\n');
+            if !rendered.is_empty() {
+                output.push_str(rendered);
+                output.push_str("\n\n");
+            }
+        }
+    }
+}
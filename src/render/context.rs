@@ -0,0 +1,117 @@
+use std::cell::Cell;
+
+use super::RenderSettings;
+
+/// The state of a single render, passed to every block alongside the
+/// document-level [`RenderSettings`].
+///
+/// Reports create a fresh context for each render, so state blocks share
+/// while rendering, such as where the last numbered list stopped, never
+/// carries over into another render. The state itself is private to the
+/// crate; blocks outside it can only read the settings.
+#[derive(Debug, Default)]
+pub struct RenderContext {
+    settings: RenderSettings,
+    last_list_number: Cell<usize>,
+    included_tags: Option<Vec<String>>,
+    spacing_policy: SpacingPolicy,
+    annotate_source: bool,
+    part_resets_numbering: bool,
+}
+
+/// How many blank lines separate blocks in the generated Typst source.
+///
+/// Blocks are always separated by at least one blank line, since Typst would
+/// otherwise merge neighboring paragraphs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpacingPolicy {
+    /// Each block ends with a blank line, and each section's blocks are
+    /// followed by one more.
+    #[default]
+    Standard,
+    /// Exactly one blank line after every block, with no extra lines after
+    /// sections.
+    Compact,
+}
+
+impl RenderContext {
+    /// Start a render that applies the given document-level settings.
+    pub(crate) fn new(settings: RenderSettings) -> Self {
+        Self {
+            settings,
+            last_list_number: Cell::new(0),
+            included_tags: None,
+            spacing_policy: SpacingPolicy::Standard,
+            annotate_source: false,
+            part_resets_numbering: false,
+        }
+    }
+
+    /// Choose how many blank lines separate rendered blocks.
+    pub(crate) fn with_spacing_policy(mut self, spacing_policy: SpacingPolicy) -> Self {
+        self.spacing_policy = spacing_policy;
+        self
+    }
+
+    /// Limit rendering to untagged sections and sections carrying one of the
+    /// given tags.
+    pub(crate) fn with_included_tags(mut self, tags: Vec<String>) -> Self {
+        self.included_tags = Some(tags);
+        self
+    }
+
+    /// Return the document-level defaults configured on the report.
+    pub fn settings(&self) -> &RenderSettings {
+        &self.settings
+    }
+
+    /// Configure whether sections are preceded by a comment naming them.
+    pub(crate) fn with_annotate_source(mut self, annotate_source: bool) -> Self {
+        self.annotate_source = annotate_source;
+        self
+    }
+
+    /// Configure whether heading numbering restarts after every part divider.
+    pub(crate) fn with_part_resets_numbering(mut self, part_resets_numbering: bool) -> Self {
+        self.part_resets_numbering = part_resets_numbering;
+        self
+    }
+
+    /// Return how many blank lines separate rendered blocks.
+    pub(crate) fn spacing_policy(&self) -> SpacingPolicy {
+        self.spacing_policy
+    }
+
+    /// Return whether sections are preceded by a comment naming them.
+    pub(crate) fn annotate_source(&self) -> bool {
+        self.annotate_source
+    }
+
+    /// Return whether heading numbering restarts after every part divider.
+    pub(crate) fn part_resets_numbering(&self) -> bool {
+        self.part_resets_numbering
+    }
+
+    /// Return whether a section with the given tags should render.
+    ///
+    /// Untagged sections always render, as does everything when no tag filter
+    /// was configured.
+    pub(crate) fn includes_tags(&self, tags: &[String]) -> bool {
+        match &self.included_tags {
+            Some(included) if !tags.is_empty() => tags.iter().any(|tag| included.contains(tag)),
+            _ => true,
+        }
+    }
+
+    /// Return the number of the last item rendered by a numbered list, or `0`
+    /// before any numbered list was rendered.
+    pub(crate) fn last_list_number(&self) -> usize {
+        self.last_list_number.get()
+    }
+
+    /// Record the number of the last item rendered by a numbered list so a
+    /// following list can continue from it.
+    pub(crate) fn set_last_list_number(&self, number: usize) {
+        self.last_list_number.set(number);
+    }
+}
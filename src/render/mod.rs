@@ -1,4 +1,8 @@
 pub(crate) mod block;
+pub(crate) mod context;
+pub(crate) mod settings;
 pub(crate) mod table;
 
-pub(crate) use block::render_blocks;
+pub(crate) use block::{render_block, render_blocks};
+pub use context::{RenderContext, SpacingPolicy};
+pub use settings::RenderSettings;
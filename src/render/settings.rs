@@ -0,0 +1,24 @@
+/// Document-level defaults that blocks can consult while rendering.
+///
+/// Reports build these settings from their configuration and pass them down to
+/// every block, so document-wide choices do not need to be repeated on each
+/// individual block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderSettings {
+    preserve_whitespace: bool,
+}
+
+impl RenderSettings {
+    /// Configure whether text keeps its leading and trailing whitespace unless
+    /// a block overrides it.
+    pub(crate) fn with_preserve_whitespace(mut self, preserve_whitespace: bool) -> Self {
+        self.preserve_whitespace = preserve_whitespace;
+        self
+    }
+
+    /// Return whether text should keep its leading and trailing whitespace by
+    /// default.
+    pub fn preserve_whitespace(&self) -> bool {
+        self.preserve_whitespace
+    }
+}
@@ -1,34 +1,66 @@
 use std::fmt::Write;
 
-pub(crate) fn table_markup(headers: &[String], rows: &[Vec<String>], include_hash: bool) -> String {
+use crate::block::CellWrap;
+
+pub(crate) fn table_markup(
+    headers: &[String],
+    rows: &[Vec<String>],
+    include_hash: bool,
+    repeat_header: bool,
+    stroke: Option<&str>,
+    gutter: Option<&str>,
+    column_wraps: &[(usize, CellWrap)],
+) -> String {
+    let cell = |idx: usize, content: &str| -> String {
+        let content = content.trim();
+        match column_wraps.iter().find(|(column, _)| *column == idx) {
+            Some((_, wrap)) => wrap.apply(content),
+            None => content.to_string(),
+        }
+    };
+
+    let row_markup = |cells: &[String]| -> String {
+        cells
+            .iter()
+            .enumerate()
+            .map(|(idx, content)| format!("[{}]", cell(idx, content)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
     let mut output = String::new();
-    let column_spec = std::iter::repeat("(flex: 1,)")
-        .take(headers.len())
-        .collect::<Vec<_>>()
-        .join(", ");
+    let mut column_spec = vec!["1fr"; headers.len()].join(", ");
+    if headers.len() == 1 {
+        // A single parenthesized value is not an array without the comma.
+        column_spec.push(',');
+    }
     let prefix = if include_hash { "#table" } else { "table" };
-    writeln!(output, "{}(columns: ({}))[", prefix, column_spec)
+    writeln!(output, "{}(\n  columns: ({}),", prefix, column_spec)
         .expect("writing to string never fails");
-    output.push_str("  [");
-    for (idx, header) in headers.iter().enumerate() {
-        if idx > 0 {
-            output.push_str("] [");
-        }
-        output.push_str(header.trim());
+    if let Some(stroke) = stroke {
+        writeln!(output, "  stroke: {},", stroke).expect("writing to string never fails");
+    }
+    if let Some(gutter) = gutter {
+        writeln!(output, "  gutter: {},", gutter).expect("writing to string never fails");
     }
-    output.push_str("]\n");
 
-    for row in rows {
-        output.push_str("  [");
-        for (idx, cell) in row.iter().enumerate() {
-            if idx > 0 {
-                output.push_str("] [");
-            }
-            output.push_str(cell.trim());
-        }
-        output.push_str("]\n");
+    // Cells are positional arguments: a trailing content block would put the
+    // whole table body into a single cell.
+    if repeat_header {
+        writeln!(
+            output,
+            "  table.header(repeat: true, {}),",
+            row_markup(headers)
+        )
+        .expect("writing to string never fails");
+    } else if !headers.is_empty() {
+        writeln!(output, "  {},", row_markup(headers)).expect("writing to string never fails");
+    }
+
+    for row in rows.iter().filter(|row| !row.is_empty()) {
+        writeln!(output, "  {},", row_markup(row)).expect("writing to string never fails");
     }
 
-    output.push_str("]\n");
+    output.push_str(")\n");
     output
 }
@@ -2,26 +2,29 @@ use std::{
     fmt::Write,
     fs,
     path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
 };
 
-use time::{OffsetDateTime, UtcOffset};
-
 use crate::{
-    block::{BlockNode, paragraph},
-    render::render_blocks,
+    block::{
+        Block, BlockNode, FigureKind, Image, IncludeBlock, TermsList, Text, footnote_label,
+        lang_arguments, paragraph, parse_lang, raw,
+    },
+    color::Color,
+    manifest::manifest_json,
+    output::OutputFormat,
+    render::{RenderContext, RenderSettings, SpacingPolicy, render_block, render_blocks},
     section::Section,
+    show_rule::ShowRule,
+    typst_escape::escape_string,
+    world::{ReportWorld, used_font_families},
 };
-use comemo::Prehashed;
 use typst::{
-    Library, World, compile,
-    diag::{FileError, FileResult},
-    eval::Tracer,
-    foundations::{Bytes, Smart},
-    syntax::{FileId, SyntaxError, parse},
-    text::{Font, FontBook},
+    model::Document,
+    syntax::{Span, SyntaxError, is_ident, parse},
 };
-use typst_assets::fonts;
-use typst_pdf::pdf;
 
 /// Represents a page-level section, such as a header or footer, composed of
 /// reusable blocks.
@@ -36,6 +39,12 @@ impl PageSection {
         Self { blocks: Vec::new() }
     }
 
+    /// Create a page section showing the current page and the total page
+    /// count, such as `3 / 12`.
+    pub fn page_counter() -> Self {
+        Self::new().add_block(raw(PAGE_COUNTER_MARKUP))
+    }
+
     /// Add a block to the section.
     pub fn add_block(mut self, block: BlockNode) -> Self {
         self.blocks.push(block);
@@ -51,6 +60,26 @@ impl PageSection {
     }
 }
 
+/// Name of the Typst function generated for a configured main outline.
+const MAIN_OUTLINE_FUNCTION: &str = "main_outline";
+
+/// Typst markup displaying the current page number out of the total pages.
+const PAGE_COUNTER_MARKUP: &str =
+    "#context [#counter(page).display() / #counter(page).final().first()]";
+
+/// Built-in figure kinds whose counters restart with per-section figure
+/// numbering.
+const FIGURE_KINDS: [&str; 3] = ["image", "table", "raw"];
+
+/// Counter stepped by every top-level section heading when
+/// [`Report::figure_numbering`] is set. Typst's own heading counter only
+/// counts numbered headings, so it cannot be used for unnumbered reports.
+const CHAPTER_COUNTER: &str = "report-chapter";
+
+/// Typst helper that unwraps the `section(body: ...)` wrapper used for page
+/// headers and footers.
+const PAGE_SECTION_FUNCTION: &str = "#let section(body: none) = body\n";
+
 impl From<&str> for PageSection {
     fn from(value: &str) -> Self {
         Self::from(value.to_string())
@@ -69,14 +98,48 @@ impl From<String> for PageSection {
 pub struct Report {
     title: String,
     author: Option<String>,
+    lang: Option<String>,
+    text_dir: Option<String>,
     header: Option<PageSection>,
     footer: Option<PageSection>,
+    show_title_heading: bool,
+    abstract_text: Option<Text>,
     include_outline: bool,
+    outline: Option<Outline>,
+    outline_position: OutlinePosition,
     include_contents_table: bool,
     include_figure_table: bool,
-    generate_pdf: bool,
+    glossary: Vec<(String, String)>,
+    sort_glossary: bool,
+    outputs: Vec<OutputFormat>,
+    pdf_output: Option<PathBuf>,
+    keep_typst: bool,
+    write_manifest: bool,
+    module_function: Option<String>,
+    check_image_paths: bool,
+    preserve_whitespace: bool,
+    sanitize_control_chars: bool,
+    spacing_policy: SpacingPolicy,
+    annotate_source: bool,
+    justify: bool,
+    heading_offset: usize,
+    promote_sections: bool,
+    section_spacing: Option<String>,
+    figure_numbering: Option<String>,
+    watermark: Option<String>,
+    page_numbering: Option<String>,
+    link_style: Option<(Color, bool)>,
+    heading_colors: Vec<(u8, Color)>,
+    part_resets_numbering: bool,
     sections: Vec<Section>,
+    includes: Vec<IncludeBlock>,
+    bibliography_inline: Option<String>,
+    preamble: Vec<BlockNode>,
+    show_rules: Vec<ShowRule>,
+    imports: Vec<(String, Vec<String>)>,
+    footnotes: Vec<(String, Text)>,
     front_matter: Vec<BlockNode>,
+    dedup_front_matter: bool,
 }
 
 impl Report {
@@ -85,20 +148,145 @@ impl Report {
         Self {
             title: title.into(),
             author: None,
+            lang: None,
+            text_dir: None,
             header: None,
             footer: None,
+            show_title_heading: true,
+            abstract_text: None,
             include_outline: true,
+            outline: None,
+            outline_position: OutlinePosition::AfterTitle,
             include_contents_table: false,
             include_figure_table: false,
-            generate_pdf: false,
+            glossary: Vec::new(),
+            sort_glossary: true,
+            outputs: vec![OutputFormat::Typst],
+            pdf_output: None,
+            keep_typst: true,
+            write_manifest: false,
+            module_function: None,
+            check_image_paths: false,
+            preserve_whitespace: false,
+            sanitize_control_chars: false,
+            spacing_policy: SpacingPolicy::Standard,
+            annotate_source: false,
+            justify: false,
+            heading_offset: 0,
+            promote_sections: false,
+            section_spacing: None,
+            figure_numbering: None,
+            watermark: None,
+            page_numbering: None,
+            link_style: None,
+            heading_colors: Vec::new(),
+            part_resets_numbering: false,
             sections: Vec::new(),
+            includes: Vec::new(),
+            bibliography_inline: None,
+            preamble: Vec::new(),
+            show_rules: Vec::new(),
+            imports: Vec::new(),
+            footnotes: Vec::new(),
             front_matter: Vec::new(),
+            dedup_front_matter: false,
         }
     }
 
     /// Configure whether a PDF should be generated alongside the Typst output.
+    ///
+    /// Enabling this adds [`OutputFormat::Pdf`] to the configured outputs and
+    /// keeps any others, such as an SVG set with [`Report::outputs`];
+    /// disabling it drops the PDF from them.
     pub fn generate_pdf(mut self, generate_pdf: bool) -> Self {
-        self.generate_pdf = generate_pdf;
+        if generate_pdf {
+            if !self.outputs.contains(&OutputFormat::Pdf) {
+                self.outputs.push(OutputFormat::Pdf);
+            }
+            self
+        } else {
+            let outputs = self
+                .outputs
+                .iter()
+                .copied()
+                .filter(|format| *format != OutputFormat::Pdf)
+                .collect::<Vec<_>>();
+            self.outputs(outputs)
+        }
+    }
+
+    /// Configure which files [`Report::render`] writes. Defaults to
+    /// [`OutputFormat::Typst`] only.
+    ///
+    /// Each file is named after the normalized title with the format's
+    /// extension, such as `weekly_status.pdf`. Compiled formats are produced
+    /// from a single compilation of the document.
+    pub fn outputs(mut self, outputs: impl IntoIterator<Item = OutputFormat>) -> Self {
+        self.outputs = Vec::new();
+        for format in outputs {
+            if !self.outputs.contains(&format) {
+                self.outputs.push(format);
+            }
+        }
+        self
+    }
+
+    /// Write the PDF to `path` instead of next to the Typst file.
+    ///
+    /// This only sets the destination; enable PDF output with
+    /// [`Report::generate_pdf`] or [`Report::outputs`].
+    pub fn pdf_output<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.pdf_output = Some(path.into());
+        self
+    }
+
+    /// Configure whether the `.typ` file is kept after compiled outputs such
+    /// as the PDF were written. Defaults to `true`.
+    ///
+    /// The file is only removed once every compiled output succeeded, so a
+    /// failed compile leaves the source behind for inspection.
+    pub fn keep_typst(mut self, keep_typst: bool) -> Self {
+        self.keep_typst = keep_typst;
+        self
+    }
+
+    /// Render the report as an importable Typst module that defines a
+    /// function returning the report body, such as `as_module("body")` for
+    /// use with `#import "report.typ": body`.
+    ///
+    /// Module output omits `#set document` and page setup (headers, footers,
+    /// and watermarks) so the importing document keeps control of them;
+    /// other set and show rules only apply inside the function's content.
+    ///
+    /// # Panics
+    /// Panics if the name is not a valid Typst identifier.
+    pub fn as_module<T: Into<String>>(mut self, function_name: T) -> Self {
+        let function_name = function_name.into();
+        assert!(
+            is_ident(&function_name),
+            "invalid Typst function name: {:?}",
+            function_name
+        );
+        self.module_function = Some(function_name);
+        self
+    }
+
+    /// Configure whether [`Report::render`] also writes a JSON manifest,
+    /// named like the outputs with a `.manifest.json` extension, listing the
+    /// title and the format, path, and byte size of every file written.
+    /// Defaults to `false`.
+    pub fn write_manifest(mut self, write_manifest: bool) -> Self {
+        self.write_manifest = write_manifest;
+        self
+    }
+
+    /// Configure whether [`Report::render_validated`] should fail when a local
+    /// image path does not exist. Defaults to `false`.
+    ///
+    /// Paths are resolved relative to the output directory, matching how the
+    /// Typst compiler resolves them. Remote URLs and data URIs are skipped.
+    pub fn check_image_paths(mut self, check_image_paths: bool) -> Self {
+        self.check_image_paths = check_image_paths;
         self
     }
 
@@ -108,6 +296,29 @@ impl Report {
         self
     }
 
+    /// Set the document-wide text language, such as `"ar"`, `"en-US"` or
+    /// `"zh-Hant-TW"`.
+    ///
+    /// Script and region subtags are emitted as Typst's separate `script` and
+    /// `region` arguments. [`Report::render_validated`] rejects tags that are
+    /// not a plausible language code, such as `"english"`.
+    pub fn lang<T: Into<String>>(mut self, lang: T) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the document-wide text direction, such as `"rtl"` or `"ltr"`.
+    ///
+    /// Individual [`Text`](crate::Text) runs can still override the direction
+    /// with [`Text::dir`](crate::Text::dir). Content aligns to the start of
+    /// the text direction, so headings, paragraphs and list markers of a
+    /// `"rtl"` report line up on the right. Without an explicit direction,
+    /// Typst derives it from the [`Report::lang`] tag.
+    pub fn text_dir<T: Into<String>>(mut self, dir: T) -> Self {
+        self.text_dir = Some(dir.into());
+        self
+    }
+
     /// Configure a page header for the report.
     pub fn header<T: Into<PageSection>>(mut self, header: T) -> Self {
         self.header = Some(header.into());
@@ -120,6 +331,26 @@ impl Report {
         self
     }
 
+    /// Configure whether the `= {title}` heading is rendered. Defaults to
+    /// `true`.
+    ///
+    /// Disable it when embedding the report body in a template that already
+    /// shows the title; the `#set document(title: ...)` metadata is kept.
+    pub fn show_title_heading(mut self, show_title_heading: bool) -> Self {
+        self.show_title_heading = show_title_heading;
+        self
+    }
+
+    /// Show an abstract in a bordered, indented box labeled "Abstract" between
+    /// the title heading and the outline.
+    ///
+    /// The content is escaped like any other text and inherits the document
+    /// font settings.
+    pub fn abstract_text<T: Into<Text>>(mut self, abstract_text: T) -> Self {
+        self.abstract_text = Some(abstract_text.into());
+        self
+    }
+
     /// Configure whether an outline should be included at the start of the
     /// rendered Typst document. Defaults to `true`.
     pub fn with_outline(mut self, include_outline: bool) -> Self {
@@ -127,6 +358,23 @@ impl Report {
         self
     }
 
+    /// Configure the main outline using an [`Outline`] builder instead of the
+    /// default `#outline()`.
+    ///
+    /// The outline is still only rendered when [`Report::with_outline`] is
+    /// enabled.
+    pub fn outline_config(mut self, outline: Outline) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    /// Choose whether the main outline comes right after the title or after
+    /// the front matter. Defaults to [`OutlinePosition::AfterTitle`].
+    pub fn outline_position(mut self, position: OutlinePosition) -> Self {
+        self.outline_position = position;
+        self
+    }
+
     /// Configure whether a table of contents should be included after the
     /// outline. Defaults to `false`.
     pub fn with_contents_table(mut self, include_contents_table: bool) -> Self {
@@ -141,109 +389,1099 @@ impl Report {
         self
     }
 
+    /// Add a "Glossary" section listing terms and their definitions, such as
+    /// abbreviations, after the contents and figure tables.
+    ///
+    /// Terms are sorted alphabetically, ignoring case, unless
+    /// [`Report::sort_glossary`] is turned off.
+    pub fn glossary<T: Into<String>, D: Into<String>>(
+        mut self,
+        entries: impl IntoIterator<Item = (T, D)>,
+    ) -> Self {
+        self.glossary = entries
+            .into_iter()
+            .map(|(term, definition)| (term.into(), definition.into()))
+            .collect();
+        self
+    }
+
+    /// Configure whether glossary terms are sorted alphabetically. Defaults to
+    /// `true`; pass `false` to keep the order they were given in.
+    pub fn sort_glossary(mut self, sort_glossary: bool) -> Self {
+        self.sort_glossary = sort_glossary;
+        self
+    }
+
+    /// Configure whether text keeps its leading and trailing whitespace by
+    /// default. Defaults to `false`, trimming text content.
+    ///
+    /// Individual [`Text`](crate::Text) values can still override this with
+    /// [`Text::preserve_whitespace`](crate::Text::preserve_whitespace).
+    pub fn preserve_whitespace(mut self, preserve_whitespace: bool) -> Self {
+        self.preserve_whitespace = preserve_whitespace;
+        self
+    }
+
+    /// Choose how many blank lines separate blocks in the generated Typst
+    /// source. Defaults to [`SpacingPolicy::Standard`].
+    pub fn spacing_policy(mut self, spacing_policy: SpacingPolicy) -> Self {
+        self.spacing_policy = spacing_policy;
+        self
+    }
+
+    /// Configure whether each section in the Typst source is preceded by a
+    /// `// section: <title>` comment, to trace generated markup back to the
+    /// section that produced it. Disabled by default.
+    ///
+    /// Typst ignores comments, so the compiled document does not change.
+    pub fn annotate_source(mut self, annotate_source: bool) -> Self {
+        self.annotate_source = annotate_source;
+        self
+    }
+
+    /// Configure whether body paragraphs are justified across the whole
+    /// document. Defaults to `false`, leaving Typst's ragged-right default in
+    /// place.
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Configure whether control characters, such as NUL or vertical tabs in
+    /// user-supplied data, are stripped from the rendered document. Defaults
+    /// to `false`.
+    ///
+    /// Newlines, carriage returns, and tabs are kept. Stripping applies to all
+    /// content, including text, list items, table cells, and captions.
+    pub fn sanitize_control_chars(mut self, sanitize_control_chars: bool) -> Self {
+        self.sanitize_control_chars = sanitize_control_chars;
+        self
+    }
+
+    /// Shift every heading in the rendered document down by `offset` levels.
+    ///
+    /// This is useful when embedding a report beneath an existing heading: with
+    /// an offset of `1`, the title renders as `==` and top-level sections as
+    /// `===`. Defaults to `0`.
+    pub fn heading_offset(mut self, offset: usize) -> Self {
+        self.heading_offset = offset;
+        self
+    }
+
+    /// Render top-level sections at the title's heading level, so a report
+    /// built from a single section shows it as the top-level heading.
+    pub(crate) fn promote_sections(mut self) -> Self {
+        self.promote_sections = true;
+        self
+    }
+
+    /// Return the depth top-level sections render at.
+    fn section_depth(&self) -> usize {
+        if self.promote_sections {
+            self.heading_offset
+        } else {
+            self.heading_offset + 1
+        }
+    }
+
+    /// Set the vertical space above top-level section headings, such as
+    /// `"2em"`.
+    ///
+    /// When unset, Typst's default heading spacing applies.
+    pub fn section_spacing<T: Into<String>>(mut self, spacing: T) -> Self {
+        self.section_spacing = Some(spacing.into());
+        self
+    }
+
+    /// Number figures per top-level section with a pattern such as `"1.1"`,
+    /// so the second figure of the third section reads `3.2`.
+    ///
+    /// The pattern receives the section number followed by the figure number,
+    /// and figure counters restart at every top-level section heading.
+    pub fn figure_numbering<T: Into<String>>(mut self, pattern: T) -> Self {
+        self.figure_numbering = Some(pattern.into());
+        self
+    }
+
+    /// Print a diagonal watermark, such as `"DRAFT"`, behind every page.
+    ///
+    /// An empty watermark leaves the pages unchanged.
+    pub fn watermark<T: Into<String>>(mut self, watermark: T) -> Self {
+        self.watermark = Some(watermark.into());
+        self
+    }
+
+    /// Number pages with a pattern such as `"1"`, `"i"`, or `"1 / 1"`, which
+    /// also sets the page labels PDF viewers show.
+    ///
+    /// This only affects page numbers; headings keep their own numbering.
+    pub fn page_numbering<T: Into<String>>(mut self, pattern: T) -> Self {
+        self.page_numbering = Some(pattern.into());
+        self
+    }
+
+    /// Color every link and optionally underline it so links stand out from
+    /// body text.
+    ///
+    /// Styling set on an individual link's [`Text`] still takes precedence.
+    pub fn link_style<C: Into<Color>>(mut self, color: C, underline: bool) -> Self {
+        self.link_style = Some((color.into(), underline));
+        self
+    }
+
+    /// Color the headings at one level, such as `heading_color(1, Color::Navy)`.
+    ///
+    /// Call this once per level; each call adds a
+    /// `#show heading.where(level: ..): set text(fill: ..)` rule.
+    pub fn heading_color<C: Into<Color>>(mut self, level: u8, color: C) -> Self {
+        self.heading_colors.push((level, color.into()));
+        self
+    }
+
+    /// Add a block that renders before the title heading, after the document
+    /// settings.
+    ///
+    /// Use this for `#set` and `#show` rules that must apply to the whole
+    /// document, such as `raw("#show heading: set text(navy)")`.
+    pub fn add_preamble_block(mut self, block: BlockNode) -> Self {
+        self.preamble.push(block);
+        self
+    }
+
+    /// Add a document-wide `#show` rule. Rules render before the title
+    /// heading in the order they were added.
+    ///
+    /// [`Report::render_validated`] reports rules that do not parse on their
+    /// own.
+    pub fn add_show_rule(mut self, rule: ShowRule) -> Self {
+        self.show_rules.push(rule);
+        self
+    }
+
+    /// Import items from another Typst file, such as shared helper functions,
+    /// with `#import "path": item1, item2`. Imports render at the top of the
+    /// document in the order they were added.
+    ///
+    /// With no items, the file is imported as a module named after it.
+    ///
+    /// # Panics
+    /// Panics if an item is not a valid Typst identifier.
+    pub fn import<P: Into<String>>(mut self, path: P, items: &[&str]) -> Self {
+        let items = items
+            .iter()
+            .map(|item| {
+                assert!(is_ident(item), "invalid Typst import item: {:?}", item);
+                item.to_string()
+            })
+            .collect();
+        self.imports.push((path.into(), items));
+        self
+    }
+
+    /// Register a footnote that can be referenced any number of times with
+    /// [`footnote_ref`](crate::footnote_ref).
+    ///
+    /// The footnote is emitted once as a hidden, labelled `#footnote` before
+    /// the title heading, and every reference reuses its number and entry.
+    /// Plain content is escaped so it renders literally.
+    ///
+    /// # Panics
+    /// Panics if the key does not form a valid Typst label.
+    pub fn define_footnote<K: Into<String>, C: Into<Text>>(mut self, key: K, content: C) -> Self {
+        self.footnotes
+            .push((footnote_label(key.into()), content.into()));
+        self
+    }
+
     /// Add content that should appear before any section headings.
     pub fn add_front_matter(mut self, block: BlockNode) -> Self {
         self.front_matter.push(block);
         self
     }
 
+    /// Append several front matter blocks at once, after any blocks that were
+    /// already added.
+    pub fn with_front_matter(mut self, blocks: impl IntoIterator<Item = BlockNode>) -> Self {
+        self.front_matter.extend(blocks);
+        self
+    }
+
+    /// Configure whether a front matter block that renders exactly like the
+    /// block before it is skipped, such as a disclaimer added twice while
+    /// assembling a report. Defaults to `false`, keeping every block.
+    pub fn dedup_front_matter(mut self, dedup_front_matter: bool) -> Self {
+        self.dedup_front_matter = dedup_front_matter;
+        self
+    }
+
+    /// Pull in the content of another Typst file, such as a chapter kept in a
+    /// file of its own, with `#include "path"` after the last section.
+    ///
+    /// Included files render in the order they were added. Relative paths
+    /// resolve against the directory of the generated `.typ` file. Use the
+    /// [`include_file`](crate::include_file) block to include a file inside a
+    /// section instead.
+    pub fn include_file<P: Into<String>>(mut self, path: P) -> Self {
+        self.includes.push(IncludeBlock::new(path));
+        self
+    }
+
+    /// Add a bibliography from a Hayagriva YAML string, so `@key` and
+    /// `#cite(<key>)` references resolve without a bibliography file on disk.
+    ///
+    /// The bibliography renders after the last section and any included
+    /// files. While compiling, the YAML is served from memory; when
+    /// [`Report::render`] writes the Typst source, it also writes the YAML to
+    /// `<title>.bibliography.yml` next to it so the source compiles on its own,
+    /// replacing any existing file of that name like the other outputs. The
+    /// file is listed in the manifest and removed with the Typst source when
+    /// only compiled outputs are kept.
+    pub fn bibliography_inline<T: Into<String>>(mut self, yaml: T) -> Self {
+        self.bibliography_inline = Some(yaml.into());
+        self
+    }
+
+    /// Return the path the inline bibliography is read from, relative to the
+    /// generated `.typ` file.
+    pub(crate) fn inline_bibliography_path(&self) -> PathBuf {
+        PathBuf::from(format!("{}.bibliography.yml", normalized_stem(&self.title)))
+    }
+
+    /// Return the YAML set with [`Report::bibliography_inline`].
+    pub(crate) fn inline_bibliography(&self) -> Option<&str> {
+        self.bibliography_inline.as_deref()
+    }
+
     /// Add a section to the report.
     pub fn add_section(mut self, section: Section) -> Self {
         self.sections.push(section);
         self
     }
 
-    /// Render the report to a Typst document string.
-    pub fn render(&self) -> String {
-        let rendered = self.render_validated().unwrap_or_else(|errors| {
-            let summary = errors
+    /// Add a part divider, such as "Part I", that renders its title on a page
+    /// of its own before the sections added after it.
+    ///
+    /// Part titles are not headings, so they do not appear in the outline.
+    pub fn add_part<T: Into<String>>(self, title: T) -> Self {
+        self.add_section(Section::part(title))
+    }
+
+    /// Configure whether heading numbering restarts after every part divider,
+    /// including for the part's own subsections. Defaults to `false`.
+    pub fn part_resets_numbering(mut self, part_resets_numbering: bool) -> Self {
+        self.part_resets_numbering = part_resets_numbering;
+        self
+    }
+
+    /// Append several sections at once, after any sections that were already
+    /// added.
+    pub fn with_sections(mut self, sections: impl IntoIterator<Item = Section>) -> Self {
+        self.sections.extend(sections);
+        self
+    }
+
+    /// Remove every section, front-matter block, and included file while
+    /// keeping the title and all document-level settings, so a configured
+    /// report can be refilled, such as on each refresh of a live dashboard.
+    pub fn clear_content(&mut self) {
+        self.sections.clear();
+        self.front_matter.clear();
+        self.includes.clear();
+    }
+
+    /// Return the number of blocks in the front matter and in every section,
+    /// including nested subsections.
+    ///
+    /// Page headers and footers, footnotes, and preamble blocks are not
+    /// counted.
+    pub fn block_count(&self) -> usize {
+        self.front_matter.len()
+            + self
+                .sections
                 .iter()
-                .map(|err| err.message.to_string())
-                .collect::<Vec<_>>()
-                .join("; ");
-            panic!("generated Typst markup contains syntax errors: {}", summary)
-        });
-
-        let file_name = typst_file_name(&self.title);
-        let file_path = std::env::current_dir()
-            .unwrap_or_else(|err| panic!("failed to resolve current directory: {}", err))
-            .join(&file_name);
-
-        fs::write(&file_path, &rendered).unwrap_or_else(|err| {
-            panic!(
-                "failed to write Typst output to {}: {}",
-                file_path.display(),
-                err
-            )
-        });
+                .map(|section| section.nested_blocks().len())
+                .sum::<usize>()
+    }
+
+    /// Walk every section depth-first in document order, yielding each with its
+    /// nesting depth.
+    ///
+    /// Top-level sections and parts have depth `0`, their subsections `1`,
+    /// and so on, regardless of [`Report::heading_offset`].
+    pub fn iter_sections(&self) -> impl Iterator<Item = (usize, &Section)> {
+        let mut stack = self
+            .sections
+            .iter()
+            .rev()
+            .map(|section| (0, section))
+            .collect::<Vec<_>>();
+
+        std::iter::from_fn(move || {
+            let (depth, section) = stack.pop()?;
+            stack.extend(
+                section
+                    .subsections()
+                    .iter()
+                    .rev()
+                    .map(|subsection| (depth + 1, subsection)),
+            );
+            Some((depth, section))
+        })
+    }
+
+    /// Return the titles of sections that would render a heading with nothing
+    /// under it, in document order.
+    ///
+    /// A section counts as empty when it has no lead, no blocks, and no
+    /// subsections. Part dividers from [`Report::add_part`] are skipped, since
+    /// the sections that follow them hold the content. Empty sections usually
+    /// point to a bug in the code that assembled the report, so check this
+    /// before rendering to catch them.
+    pub fn warn_empty_sections(&self) -> Vec<String> {
+        self.iter_sections()
+            .filter(|(_, section)| section.is_empty() && !section.is_part())
+            .map(|(_, section)| section.title().to_string())
+            .collect()
+    }
+
+    /// Return the number of sections, including parts and nested subsections.
+    pub fn section_count(&self) -> usize {
+        self.sections
+            .iter()
+            .map(Section::nested_section_count)
+            .sum()
+    }
+
+    /// Return whether [`Report::render`] writes the given format.
+    pub(crate) fn writes_format(&self, format: OutputFormat) -> bool {
+        self.outputs.contains(&format)
+    }
+
+    /// Return the path [`Report::render`] writes a format to.
+    ///
+    /// Paths derived from the title are relative to the current directory;
+    /// a PDF path set with [`Report::pdf_output`] is returned as configured.
+    pub fn output_path(&self, format: OutputFormat) -> PathBuf {
+        match (&self.pdf_output, format) {
+            (Some(pdf_output), OutputFormat::Pdf) => pdf_output.clone(),
+            _ => PathBuf::from(output_file_name(&self.title, format)),
+        }
+    }
+
+    /// Render the report to a Typst document string, writing every configured
+    /// [`OutputFormat`] to the current directory.
+    ///
+    /// # Panics
+    /// Panics if the markup is invalid, the document fails to compile, or an
+    /// output file cannot be written; use [`Report::try_render`] to handle
+    /// these as errors.
+    pub fn render(&self) -> String {
+        self.try_render().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Render the report like [`Report::render`], returning failures instead
+    /// of panicking.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::InvalidMarkup`] when validation fails,
+    /// [`RenderError::Compile`] when the Typst compiler rejects the document,
+    /// and [`RenderError::Io`] when an output file cannot be written or
+    /// removed.
+    pub fn try_render(&self) -> Result<String, RenderError> {
+        let rendered = self
+            .render_validated()
+            .map_err(RenderError::InvalidMarkup)?;
+
+        let output_dir = std::env::current_dir()
+            .map_err(|err| io_error(".", "failed to resolve current directory", err))?;
+        let file_path = output_dir.join(self.output_path(OutputFormat::Typst));
+        let mut written = Vec::new();
+
+        if self.writes_format(OutputFormat::Typst) {
+            write_output(&file_path, rendered.as_bytes(), OutputFormat::Typst)?;
+            written.push((
+                OutputFormat::Typst.extension(),
+                self.output_path(OutputFormat::Typst),
+                rendered.len(),
+            ));
+
+            if let Some(yaml) = self.inline_bibliography() {
+                let path = output_dir.join(self.inline_bibliography_path());
+                fs::write(&path, yaml)
+                    .map_err(|err| io_error(&path, "failed to write bibliography", err))?;
+                written.push(("yml", self.inline_bibliography_path(), yaml.len()));
+            }
+
+            if self.keep_typst || !self.outputs.iter().any(OutputFormat::is_compiled) {
+                written.extend(self.write_image_files(&output_dir)?);
+            }
+        }
+
+        if self.outputs.iter().any(OutputFormat::is_compiled) {
+            let document = self.world(&rendered, &file_path).try_compile_document()?;
+
+            for format in self.outputs.iter().filter(|format| format.is_compiled()) {
+                let path = output_dir.join(self.output_path(*format));
+                let bytes = format.export(&document);
+                write_output(&path, &bytes, *format)?;
+                written.push((format.extension(), self.output_path(*format), bytes.len()));
+            }
+
+            if !self.keep_typst && self.writes_format(OutputFormat::Typst) {
+                fs::remove_file(&file_path)
+                    .map_err(|err| io_error(&file_path, "failed to remove Typst output", err))?;
+                written.retain(|(format, _, _)| *format != OutputFormat::Typst.extension());
+
+                if self.bibliography_inline.is_some() {
+                    let bibliography = self.inline_bibliography_path();
+                    let path = output_dir.join(&bibliography);
+                    fs::remove_file(&path)
+                        .map_err(|err| io_error(&path, "failed to remove bibliography", err))?;
+                    written.retain(|(_, written_path, _)| *written_path != bibliography);
+                }
+            }
+        }
+
+        if self.write_manifest {
+            let manifest_path =
+                output_dir.join(format!("{}.manifest.json", normalized_stem(&self.title)));
+
+            fs::write(&manifest_path, manifest_json(&self.title, &written))
+                .map_err(|err| io_error(&manifest_path, "failed to write manifest", err))?;
+        }
+
+        Ok(rendered)
+    }
+
+    /// Render the report and compile it to PDF bytes without writing any
+    /// files.
+    ///
+    /// Relative paths, such as image paths, still resolve against the current
+    /// directory.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::InvalidMarkup`] when validation fails and
+    /// [`RenderError::Compile`] when the Typst compiler rejects the document.
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, RenderError> {
+        Ok(OutputFormat::Pdf.export(&self.compile_in_memory()?))
+    }
+
+    /// Render and compile the report without writing any output files, and
+    /// return how many pages it lays out to.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::InvalidMarkup`] when validation fails and
+    /// [`RenderError::Compile`] when the Typst compiler rejects the document.
+    pub fn page_count(&self) -> Result<usize, RenderError> {
+        Ok(self.compile_in_memory()?.pages.len())
+    }
+
+    /// Render and compile the report without writing any output files, and
+    /// return the font families its text was set in, sorted alphabetically.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::InvalidMarkup`] when validation fails and
+    /// [`RenderError::Compile`] when the Typst compiler rejects the document.
+    pub fn used_fonts(&self) -> Result<Vec<String>, RenderError> {
+        Ok(used_font_families(&self.compile_in_memory()?))
+    }
+
+    /// Render and compile the report with the current directory as the
+    /// compile root.
+    pub(crate) fn compile_in_memory(&self) -> Result<Document, RenderError> {
+        let rendered = self
+            .render_validated()
+            .map_err(RenderError::InvalidMarkup)?;
+        let main_path = PathBuf::from(output_file_name(&self.title, OutputFormat::Typst));
+
+        self.world(rendered, &main_path).try_compile_document()
+    }
 
-        if self.generate_pdf {
-            let pdf_bytes = compile_pdf(&rendered, &file_path);
-            let pdf_file = pdf_file_name(&self.title);
+    /// Build the world that compiles the rendered source, serving the inline
+    /// bibliography and [`Image::from_bytes`] images from memory.
+    fn world<S: Into<String>>(&self, rendered: S, main_path: &Path) -> ReportWorld {
+        let mut world = ReportWorld::new(rendered, main_path);
 
-            fs::write(&pdf_file, &pdf_bytes).unwrap_or_else(|err| {
-                panic!("failed to write PDF output to {}: {}", pdf_file, err)
-            });
+        if let Some(yaml) = self.inline_bibliography() {
+            world = world.with_virtual_file(self.inline_bibliography_path(), yaml);
         }
 
-        rendered
+        for image in self
+            .all_blocks()
+            .into_iter()
+            .flat_map(|block| block.images())
+        {
+            if let Some(bytes) = image.bytes() {
+                world = world.with_virtual_file(image.path(), bytes);
+            }
+        }
+
+        world
     }
 
     /// Render the report to Typst markup, returning syntax errors if the
     /// generated output is invalid Typst.
+    ///
+    /// When [`Report::check_image_paths`] is enabled, missing image files are
+    /// reported as errors before any markup is produced. Labels used more than
+    /// once across sections and blocks, and footnote references without a
+    /// matching [`Report::define_footnote`], are always reported. Every
+    /// failed check contributes its errors, so one call reports them all.
     pub fn render_validated(&self) -> Result<String, Vec<SyntaxError>> {
+        self.render_validated_with(self.render_context())
+    }
+
+    /// Render the report like [`Report::render_validated`], skipping sections
+    /// tagged with [`Section::tag`] unless one of their tags is in `include`.
+    ///
+    /// Untagged sections always render. Subsections of a skipped section are
+    /// skipped with it.
+    pub fn render_with_tags(&self, include: &[&str]) -> Result<String, Vec<SyntaxError>> {
+        let included = include.iter().map(|tag| tag.to_string()).collect();
+        self.render_validated_with(self.render_context().with_included_tags(included))
+    }
+
+    /// Validate and render the report within the given render context.
+    fn render_validated_with(&self, context: RenderContext) -> Result<String, Vec<SyntaxError>> {
+        let mut errors = Vec::new();
+        if self.check_image_paths {
+            errors.extend(self.missing_image_errors());
+        }
+        errors.extend(self.duplicate_label_errors());
+        errors.extend(self.undefined_footnote_errors());
+        errors.extend(self.invalid_show_rule_errors());
+        errors.extend(self.invalid_lang_errors());
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         let mut output = String::new();
 
-        writeln!(
-            output,
-            "#set document(title: \"{}\"{})",
-            self.title,
-            render_author(self.author.as_deref())
-        )
-        .expect("writing to string never fails");
+        self.render_preamble(&mut output, &context);
 
-        output.push_str(&contents_table_function());
-        output.push_str(&figure_table_function());
+        let title_marker = "=".repeat(self.heading_offset + 1);
+        if self.show_title_heading {
+            writeln!(output, "{} {}", title_marker, self.title)
+                .expect("writing to string never fails");
+        }
 
-        if self.header.is_some() || self.footer.is_some() {
+        if let Some(abstract_text) = &self.abstract_text {
             writeln!(
                 output,
-                "#set page({})",
-                render_page(self.header.as_ref(), self.footer.as_ref())
+                "#block(width: 100%, inset: 1em, stroke: 0.5pt + gray)[\n*Abstract*\n\n{}\n]\n",
+                abstract_text.render_markup(context.settings())
             )
             .expect("writing to string never fails");
         }
 
-        writeln!(output, "= {}", self.title).expect("writing to string never fails");
-
-        if self.include_outline {
-            output.push_str("#outline()\n\n");
+        if self.outline_position == OutlinePosition::AfterTitle {
+            self.render_outline_call(&mut output);
         }
 
         if self.include_contents_table {
-            writeln!(output, "= Table of Contents").expect("writing to string never fails");
+            writeln!(output, "{} Table of Contents", title_marker)
+                .expect("writing to string never fails");
             output.push_str("#contents_table()\n\n");
         }
 
         if self.include_figure_table {
-            writeln!(output, "= Table of Figures").expect("writing to string never fails");
+            writeln!(output, "{} Table of Figures", title_marker)
+                .expect("writing to string never fails");
             output.push_str("#figure_table()\n\n");
         }
 
-        render_blocks(&mut output, &self.front_matter, 0);
+        if !self.glossary.is_empty() {
+            writeln!(output, "{} Glossary", title_marker).expect("writing to string never fails");
+            self.glossary_list().render_with(&mut output, &context);
+        }
+
+        self.render_front_matter(&mut output, &context);
+
+        if self.outline_position == OutlinePosition::AfterFrontMatter {
+            self.render_outline_call(&mut output);
+        }
 
         for section in &self.sections {
-            section.render(&mut output, 1);
+            section.render(&mut output, self.section_depth(), &context);
         }
 
-        let parsed = parse(&output);
-        let errors = parsed.errors();
+        for include in &self.includes {
+            include.render(&mut output);
+        }
 
-        if errors.is_empty() {
-            Ok(output)
-        } else {
-            Err(errors)
+        if self.bibliography_inline.is_some() {
+            writeln!(
+                output,
+                "#bibliography(\"{}\")\n",
+                escape_string(&self.inline_bibliography_path().to_string_lossy())
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(function_name) = &self.module_function {
+            output = format!("#let {}() = [\n{}]\n", function_name, output);
+        }
+
+        if self.sanitize_control_chars {
+            output.retain(|ch| !ch.is_control() || matches!(ch, '\n' | '\r' | '\t'));
         }
+
+        validate_markup(&output).map(|()| output)
+    }
+
+    /// Write the front matter blocks, skipping consecutive duplicates when
+    /// [`Report::dedup_front_matter`] is enabled.
+    fn render_front_matter(&self, output: &mut String, context: &RenderContext) {
+        if !self.dedup_front_matter {
+            render_blocks(output, &self.front_matter, 0, context);
+            return;
+        }
+
+        let mut previous: Option<String> = None;
+        for block in &self.front_matter {
+            let mut rendered = String::new();
+            render_block(&mut rendered, block.as_ref(), context);
+
+            if previous.as_deref() != Some(rendered.as_str()) {
+                output.push_str(&rendered);
+            }
+            previous = Some(rendered);
+        }
+    }
+
+    /// Build the glossary terms list, sorting the entries when configured.
+    fn glossary_list(&self) -> TermsList {
+        let mut entries = self.glossary.iter().collect::<Vec<_>>();
+        if self.sort_glossary {
+            entries.sort_by_cached_key(|(term, _)| term.to_lowercase());
+        }
+
+        TermsList::new(
+            entries
+                .into_iter()
+                .map(|(term, definition)| (term.as_str(), definition.as_str())),
+        )
+    }
+
+    /// Write the call that places the main outline, if it is enabled.
+    fn render_outline_call(&self, output: &mut String) {
+        if self.include_outline {
+            match self.outline {
+                Some(_) => writeln!(output, "#{}()\n", MAIN_OUTLINE_FUNCTION)
+                    .expect("writing to string never fails"),
+                None => output.push_str("#outline()\n\n"),
+            }
+        }
+    }
+
+    /// Write the document-level configuration that precedes the title heading.
+    ///
+    /// # Arguments
+    /// - `output`: Mutable string that receives the rendered Typst markup.
+    /// - `context`: The current render, applied to page header and footer
+    ///   blocks.
+    fn render_preamble(&self, output: &mut String, context: &RenderContext) {
+        let standalone = self.module_function.is_none();
+
+        for (path, items) in &self.imports {
+            if items.is_empty() {
+                writeln!(output, "#import \"{}\"", escape_string(path))
+            } else {
+                writeln!(
+                    output,
+                    "#import \"{}\": {}",
+                    escape_string(path),
+                    items.join(", ")
+                )
+            }
+            .expect("writing to string never fails");
+        }
+
+        if standalone {
+            writeln!(
+                output,
+                "#set document(title: \"{}\"{})",
+                self.title,
+                render_author(self.author.as_deref())
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(text_settings) =
+            render_text_settings(self.lang.as_deref(), self.text_dir.as_deref())
+        {
+            writeln!(output, "#set text({})", text_settings)
+                .expect("writing to string never fails");
+        }
+
+        if self.justify {
+            output.push_str("#set par(justify: true)\n");
+        }
+
+        if let Some(outline) = self.outline.as_ref().filter(|_| self.include_outline) {
+            output.push_str(&outline.render_function(MAIN_OUTLINE_FUNCTION));
+        }
+
+        if self.include_contents_table {
+            output.push_str(&contents_table_function());
+        }
+
+        if self.include_figure_table {
+            output.push_str(&figure_table_function());
+        }
+
+        if standalone && (self.header.is_some() || self.footer.is_some()) {
+            output.push_str(PAGE_SECTION_FUNCTION);
+            writeln!(
+                output,
+                "#set page({})",
+                render_page(self.header.as_ref(), self.footer.as_ref(), context)
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(pattern) = self.page_numbering.as_deref().filter(|_| standalone) {
+            writeln!(
+                output,
+                "#set page(numbering: \"{}\")",
+                escape_string(pattern)
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(watermark) = self
+            .watermark
+            .as_deref()
+            .filter(|text| standalone && !text.is_empty())
+        {
+            writeln!(
+                output,
+                "#set page(background: {})",
+                render_watermark(watermark)
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some((color, underline)) = &self.link_style {
+            let styled = format!("text(fill: {}, it)", color);
+            if *underline {
+                writeln!(output, "#show link: it => underline({})", styled)
+            } else {
+                writeln!(output, "#show link: it => {}", styled)
+            }
+            .expect("writing to string never fails");
+        }
+
+        for (level, color) in &self.heading_colors {
+            writeln!(
+                output,
+                "#show heading.where(level: {}): set text(fill: {})",
+                level, color
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(spacing) = &self.section_spacing {
+            writeln!(
+                output,
+                "#show heading.where(level: {}): set block(above: {})",
+                self.section_depth() + 1,
+                spacing
+            )
+            .expect("writing to string never fails");
+        }
+
+        if let Some(pattern) = &self.figure_numbering {
+            writeln!(
+                output,
+                "#set figure(numbering: n => numbering(\"{}\", counter(\"{}\").get().first(), n))",
+                escape_string(pattern),
+                CHAPTER_COUNTER
+            )
+            .expect("writing to string never fails");
+            writeln!(
+                output,
+                "#show heading.where(level: {}): it => {{ counter(\"{}\").step(); {} it }}",
+                self.section_depth() + 1,
+                CHAPTER_COUNTER,
+                FIGURE_KINDS
+                    .iter()
+                    .map(|kind| format!("counter(figure.where(kind: {})).update(0);", kind))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+            .expect("writing to string never fails");
+        }
+
+        for rule in &self.show_rules {
+            writeln!(output, "{}", rule.markup()).expect("writing to string never fails");
+        }
+
+        for (label, content) in &self.footnotes {
+            writeln!(
+                output,
+                "#hide[#footnote[{}] <{}>]",
+                content.render_markup(context.settings()),
+                label
+            )
+            .expect("writing to string never fails");
+        }
+
+        render_blocks(output, &self.preamble, 0, context);
+    }
+
+    /// Start a render carrying the document-level settings passed to every
+    /// rendered block.
+    fn render_context(&self) -> RenderContext {
+        RenderContext::new(
+            RenderSettings::default().with_preserve_whitespace(self.preserve_whitespace),
+        )
+        .with_spacing_policy(self.spacing_policy)
+        .with_annotate_source(self.annotate_source)
+        .with_part_resets_numbering(self.part_resets_numbering)
+    }
+
+    /// Collect every block in the report, including page chrome, front matter,
+    /// and nested sections.
+    pub(crate) fn all_blocks(&self) -> Vec<&BlockNode> {
+        let mut blocks = Vec::new();
+
+        for page_section in [&self.header, &self.footer].into_iter().flatten() {
+            blocks.extend(page_section.blocks());
+        }
+
+        blocks.extend(&self.preamble);
+        blocks.extend(&self.front_matter);
+
+        for section in &self.sections {
+            blocks.extend(section.nested_blocks());
+        }
+
+        blocks
+    }
+
+    /// Build an error for every footnote referenced without a matching
+    /// [`Report::define_footnote`].
+    fn undefined_footnote_errors(&self) -> Vec<SyntaxError> {
+        let mut undefined = Vec::new();
+
+        for label in self
+            .all_blocks()
+            .into_iter()
+            .flat_map(|block| block.footnote_refs())
+        {
+            let defined = self.footnotes.iter().any(|(defined, _)| defined == label);
+            if !defined && !undefined.contains(&label) {
+                undefined.push(label);
+            }
+        }
+
+        undefined
+            .into_iter()
+            .map(|label| {
+                let key = label.strip_suffix("-note").unwrap_or(label);
+                validation_error(format!("footnote reference without definition: {}", key))
+            })
+            .collect()
+    }
+
+    /// Build an error for every show rule that does not parse on its own.
+    fn invalid_show_rule_errors(&self) -> Vec<SyntaxError> {
+        self.show_rules
+            .iter()
+            .filter_map(|rule| {
+                rule.validate().err().map(|errors| {
+                    let summary = errors
+                        .iter()
+                        .map(|err| err.message.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    validation_error(format!(
+                        "invalid show rule `{}`: {}",
+                        rule.markup(),
+                        summary
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Build an error for every language tag, on the report or on text in
+    /// its blocks, that is not a plausible language tag, naming each invalid
+    /// tag once.
+    fn invalid_lang_errors(&self) -> Vec<SyntaxError> {
+        let text_langs = self
+            .abstract_text
+            .iter()
+            .chain(self.sections.iter().flat_map(Section::nested_leads))
+            .filter_map(Text::lang_tag);
+        let block_langs = self
+            .all_blocks()
+            .into_iter()
+            .flat_map(|block| block.langs());
+
+        let mut invalid = Vec::new();
+        for lang in self
+            .lang
+            .as_deref()
+            .into_iter()
+            .chain(text_langs)
+            .chain(block_langs)
+        {
+            if parse_lang(lang).is_none() && !invalid.contains(&lang) {
+                invalid.push(lang);
+            }
+        }
+
+        invalid
+            .into_iter()
+            .map(|lang| validation_error(format!("invalid language tag: {:?}", lang)))
+            .collect()
+    }
+
+    /// Build an error for every label that is attached more than once, naming
+    /// each duplicate label once.
+    fn duplicate_label_errors(&self) -> Vec<SyntaxError> {
+        let mut labels = self
+            .footnotes
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect::<Vec<_>>();
+        labels.extend(
+            self.all_blocks()
+                .into_iter()
+                .flat_map(|block| block.labels()),
+        );
+
+        for section in &self.sections {
+            labels.extend(section.heading_labels());
+        }
+
+        let mut seen = Vec::new();
+        let mut duplicates = Vec::new();
+
+        for label in labels {
+            if seen.contains(&label) {
+                if !duplicates.contains(&label) {
+                    duplicates.push(label);
+                }
+            } else {
+                seen.push(label);
+            }
+        }
+
+        duplicates
+            .into_iter()
+            .map(|label| validation_error(format!("duplicate label: <{}>", label)))
+            .collect()
+    }
+
+    /// Write the bytes of every [`Image::from_bytes`] image next to the kept
+    /// Typst source, returning the format, path, and byte size of each file
+    /// written.
+    ///
+    /// # Arguments
+    /// - `root`: Directory the generated Typst source resolves images against.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::Io`] if an image file cannot be written.
+    fn write_image_files(&self, root: &Path) -> Result<Vec<(&str, PathBuf, usize)>, RenderError> {
+        let mut written: Vec<(&str, PathBuf, usize)> = Vec::new();
+
+        for image in self
+            .all_blocks()
+            .into_iter()
+            .flat_map(|block| block.images())
+        {
+            let Some(bytes) = image.bytes() else {
+                continue;
+            };
+            if written
+                .iter()
+                .any(|(_, path, _)| path.as_path() == Path::new(image.path()))
+            {
+                continue;
+            }
+
+            let path = root.join(image.path());
+            fs::write(&path, bytes)
+                .map_err(|err| io_error(&path, "failed to write image file", err))?;
+            written.push((
+                image.bytes_format(),
+                PathBuf::from(image.path()),
+                bytes.len(),
+            ));
+        }
+
+        Ok(written)
+    }
+
+    /// Build an error for every local image path that does not exist relative
+    /// to the output directory.
+    fn missing_image_errors(&self) -> Vec<SyntaxError> {
+        let output_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+
+        self.all_blocks()
+            .into_iter()
+            .flat_map(|block| block.images())
+            .filter(|image| image.bytes().is_none())
+            .map(Image::path)
+            .filter(|path| !is_remote_image(path))
+            .filter(|path| !output_dir.join(path.trim_start_matches('/')).exists())
+            .map(|path| validation_error(format!("image file not found: {}", path)))
+            .collect()
+    }
+}
+
+/// Write a rendered output file.
+///
+/// # Arguments
+/// - `path`: Destination file path.
+/// - `contents`: Bytes to write.
+/// - `format`: Format of the output, used in the error message.
+fn write_output(path: &Path, contents: &[u8], format: OutputFormat) -> Result<(), RenderError> {
+    fs::write(path, contents)
+        .map_err(|err| io_error(path, format!("failed to write {:?} output", format), err))
+}
+
+/// Build a [`RenderError::Io`] describing a failed file operation.
+///
+/// # Arguments
+/// - `path`: File the operation was applied to.
+/// - `action`: What was attempted, such as `"failed to write manifest"`.
+/// - `err`: Error returned by the operation.
+fn io_error<P: AsRef<Path>, A: std::fmt::Display>(
+    path: P,
+    action: A,
+    err: std::io::Error,
+) -> RenderError {
+    RenderError::Io {
+        path: path.as_ref().to_path_buf(),
+        message: format!("{}: {}", action, err),
+    }
+}
+
+/// Return whether an image path points to remote or inline data rather than
+/// a file on disk.
+///
+/// # Arguments
+/// - `path`: Image path as passed to the image block.
+pub(crate) fn is_remote_image(path: &str) -> bool {
+    ["http://", "https://", "data:"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+}
+
+/// Build a validation error that is not tied to a location in the markup.
+///
+/// # Arguments
+/// - `message`: Human-readable description of the problem.
+fn validation_error(message: String) -> SyntaxError {
+    SyntaxError {
+        span: Span::detached(),
+        message: message.into(),
+        hints: Default::default(),
     }
 }
 
@@ -258,43 +1496,102 @@ fn render_author(author: Option<&str>) -> String {
     }
 }
 
+/// Render the document-wide text arguments, if any are configured.
+///
+/// # Arguments
+/// - `lang`: Optional language tag, emitted as quoted `lang`, `script` and
+///   `region` strings.
+/// - `dir`: Optional text direction, emitted as a raw Typst value.
+fn render_text_settings(lang: Option<&str>, dir: Option<&str>) -> Option<String> {
+    let mut parts = Vec::new();
+
+    if let Some(lang) = lang {
+        parts.extend(lang_arguments(lang));
+    }
+
+    if let Some(dir) = dir {
+        parts.push(format!("dir: {}", dir));
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
 /// Render the page metadata for Typst using optional header and footer
 /// sections.
 ///
 /// # Arguments
 /// - `header`: Optional page header content.
 /// - `footer`: Optional page footer content.
-fn render_page(header: Option<&PageSection>, footer: Option<&PageSection>) -> String {
+/// - `context`: The current render, applied to the page blocks.
+fn render_page(
+    header: Option<&PageSection>,
+    footer: Option<&PageSection>,
+    context: &RenderContext,
+) -> String {
     let mut parts = Vec::new();
 
     if let Some(header_content) = header {
-        parts.push(format!("header: {}", render_page_section(header_content)));
+        parts.push(format!(
+            "header: {}",
+            render_page_section(header_content, context)
+        ));
     }
 
     if let Some(footer_content) = footer {
-        parts.push(format!("footer: {}", render_page_section(footer_content)));
+        parts.push(format!(
+            "footer: {}",
+            render_page_section(footer_content, context)
+        ));
     }
 
     parts.join(", ")
 }
 
+/// Render the rotated watermark text placed in the page background.
+///
+/// # Arguments
+/// - `watermark`: Watermark text, escaped as a Typst string.
+fn render_watermark(watermark: &str) -> String {
+    format!(
+        "rotate(-45deg, text(size: 72pt, fill: luma(85%), \"{}\"))",
+        escape_string(watermark)
+    )
+}
+
 /// Render a page section into Typst markup.
 ///
 /// # Arguments
 /// - `section`: Page section to render.
-fn render_page_section(section: &PageSection) -> String {
+/// - `context`: The current render, applied to the section blocks.
+fn render_page_section(section: &PageSection, context: &RenderContext) -> String {
     let mut body = String::new();
-    render_blocks(&mut body, section.blocks(), 0);
+    render_blocks(&mut body, section.blocks(), 0, context);
 
     format!("section(body: [{}])", body.trim())
 }
 
+/// Where the main outline is placed relative to the report's opening content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutlinePosition {
+    /// Right after the title heading and abstract, before the front matter.
+    #[default]
+    AfterTitle,
+    /// After the front matter, right before the first section.
+    AfterFrontMatter,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Outline {
     title: Option<String>,
     target: Option<String>,
     indent: Option<String>,
     depth: Option<u8>,
+    fill: Option<String>,
+    entry_styles: Vec<(u8, String)>,
 }
 
 impl Outline {
@@ -327,6 +1624,24 @@ impl Outline {
         self
     }
 
+    /// Set the content Typst uses to fill the space between entries and page
+    /// numbers, such as `repeat[.]` or `none`.
+    pub fn fill<T: Into<String>>(mut self, fill: T) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Style the outline entries at a heading level with a show rule, such as
+    /// `entry_style(1, "strong")` for bold top-level entries.
+    ///
+    /// The rule is emitted as `show outline.entry.where(level: ..): ..` inside
+    /// the generated outline function, so it only styles this outline and
+    /// leaves other outlines, such as the table of figures, unchanged.
+    pub fn entry_style<T: Into<String>>(mut self, level: u8, rule: T) -> Self {
+        self.entry_styles.push((level, rule.into()));
+        self
+    }
+
     /// Convenience constructor for a table of contents outline.
     pub fn table_of_contents() -> Self {
         Self::new().title("none").indent("auto")
@@ -337,6 +1652,12 @@ impl Outline {
         Self::new().title("none").target("figure").indent("auto")
     }
 
+    /// Convenience constructor for a figure list limited to one kind of
+    /// figure, such as a list of images that sits next to a list of tables.
+    pub fn figures_of_kind(kind: FigureKind) -> Self {
+        Self::figure_list().target(format!("figure.where(kind: {})", kind))
+    }
+
     /// Render a Typst function that encapsulates the outline configuration.
     ///
     /// # Arguments
@@ -360,11 +1681,31 @@ impl Outline {
             params.push(format!("  depth: {}", depth));
         }
 
-        if params.is_empty() {
-            format!("#let {name}() = outline()\n\n")
+        if let Some(fill) = &self.fill {
+            params.push(format!("  fill: {}", fill));
+        }
+
+        let outline = if params.is_empty() {
+            "outline()".to_string()
         } else {
-            format!("#let {name}() = outline(\n{}\n)\n\n", params.join(",\n"))
+            format!("outline(\n{}\n)", params.join(",\n"))
+        };
+
+        if self.entry_styles.is_empty() {
+            return format!("#let {name}() = {outline}\n\n");
+        }
+
+        // Show rules inside the function body only reach this outline.
+        let mut body = String::new();
+        for (level, rule) in &self.entry_styles {
+            writeln!(
+                body,
+                "  show outline.entry.where(level: {}): {}",
+                level, rule
+            )
+            .expect("writing to string never fails");
         }
+        format!("#let {name}() = {{\n{body}  {outline}\n}}\n\n")
     }
 }
 
@@ -376,14 +1717,13 @@ fn figure_table_function() -> String {
     Outline::figure_list().render_function("figure_table")
 }
 
-/// Build the Typst filename derived from the report title.
-fn typst_file_name(title: &str) -> String {
-    format!("{}.typ", normalized_stem(title))
-}
-
-/// Build the PDF filename derived from the report title.
-fn pdf_file_name(title: &str) -> String {
-    format!("{}.pdf", normalized_stem(title))
+/// Build the output filename for a format, derived from the report title.
+///
+/// # Arguments
+/// - `title`: Report title to normalize into the file stem.
+/// - `format`: Output format providing the file extension.
+fn output_file_name(title: &str, format: OutputFormat) -> String {
+    format!("{}.{}", normalized_stem(title), format.extension())
 }
 
 /// Normalize the report title into a filesystem-friendly stem.
@@ -393,8 +1733,6 @@ fn normalized_stem(title: &str) -> String {
         .map(|ch| {
             if ch.is_ascii_alphanumeric() {
                 ch.to_ascii_lowercase()
-            } else if ch.is_whitespace() || ch == '-' {
-                '_'
             } else {
                 '_'
             }
@@ -407,143 +1745,107 @@ fn normalized_stem(title: &str) -> String {
         .collect::<Vec<_>>()
         .join("_");
 
-    let stem = if compacted.is_empty() {
+    if compacted.is_empty() {
         "report".to_string()
     } else {
         compacted
-    };
-
-    stem
-}
-
-struct InMemoryWorld {
-    source: typst::syntax::Source,
-    library: Prehashed<Library>,
-    book: Prehashed<FontBook>,
-    fonts: Vec<Font>,
-    root: PathBuf,
+    }
 }
 
-impl InMemoryWorld {
-    /// Create an in-memory Typst world that can compile the provided source.
-    ///
-    /// # Arguments
-    /// - `source`: Typst source code to compile.
-    /// - `main_path`: Path to the virtual entrypoint for Typst diagnostics.
-    fn new(source: String, main_path: PathBuf) -> Self {
-        let base_root = main_path
-            .parent()
-            .map(Path::to_path_buf)
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        let root = base_root
-            .canonicalize()
-            .unwrap_or(base_root);
-
-        let main_id = FileId::new(
-            None,
-            typst::syntax::VirtualPath::within_root(&main_path, &root)
-                .unwrap_or_else(|| typst::syntax::VirtualPath::new(&main_path)),
-        );
-
-        let source = typst::syntax::Source::new(main_id, source);
-
-        let fonts: Vec<Font> = fonts()
-            .flat_map(|data| Font::iter(Bytes::from(data.to_vec())))
-            .collect();
-        let book = FontBook::from_fonts(&fonts);
+/// Check that Typst markup parses without syntax errors.
+///
+/// This is the same check [`Report::render_validated`] applies to generated
+/// documents, and is useful for validating hand-written [`raw`] content.
+///
+/// # Arguments
+/// - `source`: Typst markup to parse.
+///
+/// # Errors
+/// Returns every syntax error found in the markup.
+pub fn validate_markup(source: &str) -> Result<(), Vec<SyntaxError>> {
+    let errors = parse(source).errors();
 
-        Self {
-            source,
-            library: Prehashed::new(Library::default()),
-            book: Prehashed::new(book),
-            fonts,
-            root,
-        }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
     }
 }
 
-impl World for InMemoryWorld {
-    fn library(&self) -> &Prehashed<Library> {
-        &self.library
-    }
-
-    fn book(&self) -> &Prehashed<FontBook> {
-        &self.book
-    }
+/// Compile Typst source into a PDF using the given file path as the Typst
+/// entrypoint.
+pub fn compile_pdf(source: &str, main_path: &Path) -> Vec<u8> {
+    ReportWorld::new(source, main_path).compile_pdf()
+}
 
-    fn main(&self) -> typst::syntax::Source {
-        self.source.clone()
-    }
+/// Error returned when a report or Typst source fails to render or compile.
+#[derive(Debug, Clone)]
+pub enum RenderError {
+    /// The generated markup failed validation.
+    InvalidMarkup(Vec<SyntaxError>),
+    /// The Typst compiler rejected the document, with its messages.
+    Compile(String),
+    /// Compilation did not finish within the given limit.
+    TimedOut(Duration),
+    /// An output file could not be written or removed.
+    Io { path: PathBuf, message: String },
+}
 
-    fn source(&self, id: FileId) -> FileResult<typst::syntax::Source> {
-        if id == self.source.id() {
-            return Ok(self.source.clone());
+impl std::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderError::InvalidMarkup(errors) => {
+                let summary = errors
+                    .iter()
+                    .map(|err| err.message.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                write!(f, "generated Typst markup is invalid: {}", summary)
+            }
+            RenderError::Compile(message) => write!(f, "Typst compilation failed: {}", message),
+            RenderError::TimedOut(limit) => {
+                write!(f, "Typst compilation timed out after {:?}", limit)
+            }
+            RenderError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
         }
-
-        let path = self.root.join(id.vpath().as_rootless_path());
-
-        let text = fs::read_to_string(&path)
-            .map_err(|_| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))?;
-
-        Ok(typst::syntax::Source::new(id, text))
-    }
-
-    fn file(&self, id: FileId) -> FileResult<Bytes> {
-        let path = self.root.join(id.vpath().as_rootless_path());
-
-        fs::read(path)
-            .map(Bytes::from)
-            .map_err(|_| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))
-    }
-
-    fn font(&self, index: usize) -> Option<Font> {
-        self.fonts.get(index).cloned()
     }
+}
 
-    fn today(&self, offset: Option<i64>) -> Option<typst::foundations::Datetime> {
-        let now = match offset {
-            Some(hours) => {
-                let seconds = hours.checked_mul(3600)?;
-                let utc_offset = UtcOffset::from_whole_seconds(seconds.try_into().ok()?).ok()?;
-                OffsetDateTime::now_utc().to_offset(utc_offset)
-            }
-            None => OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc()),
-        };
+impl std::error::Error for RenderError {}
 
-        typst::foundations::Datetime::from_ymd_hms(
-            now.year(),
-            now.month() as u8,
-            now.day(),
-            now.hour(),
-            now.minute(),
-            now.second(),
-        )
+/// Compile Typst source into a PDF on a worker thread, giving up after
+/// `timeout`.
+///
+/// Use this when compiling untrusted input that could otherwise hang the
+/// caller. A timed-out worker is detached and left to finish on its own;
+/// its result is discarded.
+///
+/// # Arguments
+/// - `source`: Typst source code to compile.
+/// - `main_path`: Path to the entrypoint used for diagnostics and imports.
+/// - `timeout`: Longest time to wait for the compilation.
+///
+/// # Errors
+/// Returns [`RenderError::TimedOut`] when the limit is exceeded and
+/// [`RenderError::Compile`] when the document does not compile.
+pub fn compile_pdf_with_timeout(
+    source: &str,
+    main_path: &Path,
+    timeout: Duration,
+) -> Result<Vec<u8>, RenderError> {
+    let (sender, receiver) = mpsc::channel();
+    let world = ReportWorld::new(source, main_path);
+
+    thread::spawn(move || {
+        // The receiver is gone if the caller already timed out.
+        let _ = sender.send(world.try_compile_pdf());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(RenderError::TimedOut(timeout)),
+        Err(RecvTimeoutError::Disconnected) => Err(RenderError::Compile(
+            "compilation worker exited without a result".to_string(),
+        )),
     }
 }
-
-/// Compile Typst source into a PDF using the given file path as the Typst
-/// entrypoint.
-pub fn compile_pdf(source: &str, main_path: &Path) -> Vec<u8> {
-    let main_path = if main_path.is_absolute() {
-        main_path.to_path_buf()
-    } else {
-        std::env::current_dir()
-            .unwrap_or_else(|_| PathBuf::from("."))
-            .join(main_path)
-    };
-
-    // Normalize the entrypoint path so that the Typst compiler resolves imports
-    // relative to the actual location of the input file, even when callers pass
-    // a path containing relative segments like "../".
-    let main_path = main_path
-        .canonicalize()
-        .unwrap_or(main_path);
-
-    let world = InMemoryWorld::new(source.to_string(), main_path);
-    let mut tracer = Tracer::new();
-    let document = compile(&world, &mut tracer)
-        .unwrap_or_else(|err| panic!("failed to compile Typst document to PDF: {err:?}"));
-
-    pdf(&document, Smart::Auto, None)
-}
@@ -1,13 +1,23 @@
 use std::fmt::Write;
 
-use crate::{block::BlockNode, render::render_blocks};
+use crate::{
+    block::{BlockNode, Text, validated_label},
+    render::{RenderContext, render_blocks},
+    report::Report,
+    typst_escape::escape_markup,
+};
 
 /// A section with a heading and a list of content blocks.
 #[derive(Debug, Default)]
 pub struct Section {
     title: String,
+    label: Option<String>,
+    lead: Option<Text>,
     blocks: Vec<BlockNode>,
     subsections: Vec<Section>,
+    part: bool,
+    tags: Vec<String>,
+    start_on_new_page: bool,
 }
 
 impl Section {
@@ -15,11 +25,93 @@ impl Section {
     pub fn new<T: Into<String>>(title: T) -> Self {
         Self {
             title: title.into(),
+            label: None,
+            lead: None,
             blocks: Vec::new(),
             subsections: Vec::new(),
+            part: false,
+            tags: Vec::new(),
+            start_on_new_page: false,
         }
     }
 
+    /// Create a part divider, such as "Part I", that renders its title large
+    /// and centered on a page of its own instead of as a heading.
+    ///
+    /// Blocks and subsections added to a part render after the divider page.
+    /// Part titles are not headings, so they do not appear in the outline.
+    pub fn part<T: Into<String>>(title: T) -> Self {
+        Self {
+            part: true,
+            ..Self::new(title)
+        }
+    }
+
+    /// Return the section's title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Return the subsections nested directly under this section.
+    pub fn subsections(&self) -> &[Section] {
+        &self.subsections
+    }
+
+    /// Wrap this section and its subsections in a report of their own, such
+    /// as to preview one section while editing.
+    ///
+    /// The report takes `title` as its document title but shows no title
+    /// heading; the section itself renders as the top-level `=` heading, with
+    /// its subsections one level below.
+    pub fn into_report<T: Into<String>>(self, title: T) -> Report {
+        Report::new(title)
+            .show_title_heading(false)
+            .promote_sections()
+            .add_section(self)
+    }
+
+    /// Attach a `<label>` to the section heading so it can be referenced
+    /// elsewhere.
+    ///
+    /// # Panics
+    /// Panics if the label is not a valid Typst label.
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(validated_label(label.into()));
+        self
+    }
+
+    /// Set an emphasized lead paragraph that renders right after the heading,
+    /// before any other blocks.
+    pub fn lead<T: Into<Text>>(mut self, lead: T) -> Self {
+        self.lead = Some(lead.into());
+        self
+    }
+
+    /// Begin the section on a fresh page by emitting a weak page break before
+    /// its heading.
+    ///
+    /// The flag only applies to this section; subsections keep flowing on
+    /// the current page unless they set it themselves.
+    pub fn start_on_new_page(mut self, start_on_new_page: bool) -> Self {
+        self.start_on_new_page = start_on_new_page;
+        self
+    }
+
+    /// Tag the section for an audience, such as `"internal"`, so
+    /// [`Report::render_with_tags`](crate::Report::render_with_tags) can
+    /// include or skip it. Call this several times to add more tags.
+    ///
+    /// Untagged sections always render.
+    pub fn tag<T: Into<String>>(mut self, tag: T) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Return whether the section renders under the render's tag filter.
+    pub(crate) fn is_included(&self, context: &RenderContext) -> bool {
+        context.includes_tags(&self.tags)
+    }
+
     /// Add a block of content to the section.
     pub fn add_block(mut self, block: BlockNode) -> Self {
         self.blocks.push(block);
@@ -32,15 +124,150 @@ impl Section {
         self
     }
 
-    pub(crate) fn render(&self, output: &mut String, depth: usize) {
-        let heading_level = "=".repeat(depth + 1);
-        writeln!(output, "{} {}", heading_level, self.title)
+    /// Collect the blocks of this section and all nested subsections in
+    /// document order.
+    pub(crate) fn nested_blocks(&self) -> Vec<&BlockNode> {
+        let mut blocks = self.blocks.iter().collect::<Vec<_>>();
+
+        for subsection in &self.subsections {
+            blocks.extend(subsection.nested_blocks());
+        }
+
+        blocks
+    }
+
+    /// Collect the leads of this section and all nested subsections in
+    /// document order.
+    pub(crate) fn nested_leads(&self) -> Vec<&Text> {
+        let mut leads = self.lead.iter().collect::<Vec<_>>();
+
+        for subsection in &self.subsections {
+            leads.extend(subsection.nested_leads());
+        }
+
+        leads
+    }
+
+    /// Return whether the section has neither a lead, blocks, nor subsections,
+    /// so only its heading would render.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.lead.is_none() && self.blocks.is_empty() && self.subsections.is_empty()
+    }
+
+    /// Return whether this section is a part divider created with
+    /// [`Section::part`].
+    pub(crate) fn is_part(&self) -> bool {
+        self.part
+    }
+
+    /// Count this section and all of its nested subsections.
+    pub(crate) fn nested_section_count(&self) -> usize {
+        1 + self
+            .subsections
+            .iter()
+            .map(Section::nested_section_count)
+            .sum::<usize>()
+    }
+
+    /// Collect the labels attached to the headings of this section and all
+    /// nested subsections in document order.
+    pub(crate) fn heading_labels(&self) -> Vec<&str> {
+        let mut labels = self.label.as_deref().into_iter().collect::<Vec<_>>();
+
+        for subsection in &self.subsections {
+            labels.extend(subsection.heading_labels());
+        }
+
+        labels
+    }
+
+    pub(crate) fn render(&self, output: &mut String, depth: usize, context: &RenderContext) {
+        if !self.is_included(context) {
+            return;
+        }
+
+        if context.annotate_source() {
+            // Newlines would end the comment early and leak the rest of the
+            // title into the document.
+            writeln!(
+                output,
+                "// section: {}",
+                self.title.replace(['\r', '\n'], " ")
+            )
+            .expect("writing to string never fails");
+        }
+
+        if self.part {
+            self.render_part_divider(output);
+
+            if context.part_resets_numbering() {
+                output.push_str("#counter(heading).update(0)\n\n");
+            }
+        } else {
+            if self.start_on_new_page {
+                output.push_str("#pagebreak(weak: true)\n");
+            }
+            self.render_heading(output, depth);
+        }
+
+        self.render_body(output, depth, context);
+    }
+
+    /// Render the section's content without its heading.
+    ///
+    /// Use this to place a section's blocks inside another element, such as
+    /// a callout or figure.
+    pub fn body_markup(&self) -> String {
+        let mut output = String::new();
+        self.render_body(&mut output, 1, &RenderContext::default());
+        output
+    }
+
+    /// Write the lead, blocks, and subsections, skipping the section's own
+    /// heading or part divider.
+    pub(crate) fn render_body(&self, output: &mut String, depth: usize, context: &RenderContext) {
+        if let Some(lead) = &self.lead {
+            writeln!(
+                output,
+                "#emph[{}]\n",
+                lead.render_markup(context.settings())
+            )
             .expect("writing to string never fails");
+        }
 
-        render_blocks(output, &self.blocks, depth);
+        render_blocks(output, &self.blocks, depth, context);
 
         for subsection in &self.subsections {
-            subsection.render(output, depth + 1);
+            subsection.render(output, depth + 1, context);
+        }
+    }
+
+    /// Write the full-page divider shown for a part.
+    fn render_part_divider(&self, output: &mut String) {
+        output.push_str("#pagebreak(weak: true)\n");
+        write!(
+            output,
+            "#align(center + horizon)[#text(size: 2.5em, weight: \"bold\")[{}]]",
+            escape_markup(&self.title)
+        )
+        .expect("writing to string never fails");
+
+        if let Some(label) = &self.label {
+            write!(output, " <{}>", label).expect("writing to string never fails");
         }
+
+        output.push_str("\n#pagebreak()\n\n");
+    }
+
+    /// Write the section heading at the given depth.
+    fn render_heading(&self, output: &mut String, depth: usize) {
+        let heading_level = "=".repeat(depth + 1);
+        write!(output, "{} {}", heading_level, self.title).expect("writing to string never fails");
+
+        if let Some(label) = &self.label {
+            write!(output, " <{}>", label).expect("writing to string never fails");
+        }
+
+        output.push('\n');
     }
 }
@@ -0,0 +1,60 @@
+use crate::report::validate_markup;
+use typst::syntax::SyntaxError;
+
+/// A Typst `#show <selector>: <body>` rule applied to the whole document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowRule {
+    selector: String,
+    body: String,
+}
+
+impl ShowRule {
+    /// Create a show rule, such as `ShowRule::new("raw", "set text(size: 9pt)")`.
+    ///
+    /// # Arguments
+    /// - `selector`: Elements the rule applies to, such as `table` or
+    ///   `heading.where(level: 2)`.
+    /// - `body`: Transformation applied to matching elements.
+    pub fn new<S: Into<String>, B: Into<String>>(selector: S, body: B) -> Self {
+        Self {
+            selector: selector.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Render the rule as a single line of Typst markup, without a trailing
+    /// newline.
+    pub fn markup(&self) -> String {
+        format!("#show {}: {}", self.selector, self.body)
+    }
+
+    /// Check that the rule parses on its own.
+    ///
+    /// # Errors
+    /// Returns the syntax errors found in the assembled rule.
+    pub(crate) fn validate(&self) -> Result<(), Vec<SyntaxError>> {
+        validate_markup(&self.markup())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_selector_and_body() {
+        let rule = ShowRule::new("heading.where(level: 2)", "set text(navy)");
+
+        assert_eq!(
+            rule.markup(),
+            "#show heading.where(level: 2): set text(navy)"
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_body() {
+        let rule = ShowRule::new("table", "set text(size: 9pt");
+
+        assert!(rule.validate().is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use std::rc::Rc;
+
+use crate::{
+    color::Color,
+    report::{PageSection, Report},
+    show_rule::ShowRule,
+};
+
+/// Builds the page header or footer for each report made from a template.
+type PageSectionBuilder = Rc<dyn Fn() -> PageSection>;
+
+/// Shared settings for a family of reports, such as a team's header, footer,
+/// author, and styling.
+///
+/// Call [`ReportTemplate::build`] once per report; every report receives its
+/// own copy of the settings and can be customized further without affecting
+/// the template or its other reports.
+#[derive(Clone, Default)]
+pub struct ReportTemplate {
+    author: Option<String>,
+    lang: Option<String>,
+    text_dir: Option<String>,
+    header: Option<PageSectionBuilder>,
+    footer: Option<PageSectionBuilder>,
+    watermark: Option<String>,
+    link_style: Option<(Color, bool)>,
+    heading_colors: Vec<(u8, Color)>,
+    section_spacing: Option<String>,
+    show_rules: Vec<ShowRule>,
+}
+
+impl ReportTemplate {
+    /// Create a template without any shared settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the author applied to every report, as with [`Report::author`].
+    pub fn author<T: Into<String>>(mut self, author: T) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Set the text language applied to every report, as with
+    /// [`Report::lang`].
+    pub fn lang<T: Into<String>>(mut self, lang: T) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the text direction applied to every report, as with
+    /// [`Report::text_dir`].
+    pub fn text_dir<T: Into<String>>(mut self, dir: T) -> Self {
+        self.text_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the page header applied to every report, such as
+    /// `header(|| "Internal".into())`.
+    ///
+    /// The builder runs once per report, so each report owns its header
+    /// blocks.
+    pub fn header<F: Fn() -> PageSection + 'static>(mut self, header: F) -> Self {
+        self.header = Some(Rc::new(header));
+        self
+    }
+
+    /// Set the page footer applied to every report, such as
+    /// `footer(PageSection::page_counter)`.
+    ///
+    /// The builder runs once per report, so each report owns its footer
+    /// blocks.
+    pub fn footer<F: Fn() -> PageSection + 'static>(mut self, footer: F) -> Self {
+        self.footer = Some(Rc::new(footer));
+        self
+    }
+
+    /// Set the watermark applied to every report, as with
+    /// [`Report::watermark`].
+    pub fn watermark<T: Into<String>>(mut self, watermark: T) -> Self {
+        self.watermark = Some(watermark.into());
+        self
+    }
+
+    /// Set the link styling applied to every report, as with
+    /// [`Report::link_style`].
+    pub fn link_style<C: Into<Color>>(mut self, color: C, underline: bool) -> Self {
+        self.link_style = Some((color.into(), underline));
+        self
+    }
+
+    /// Color the headings at one level in every report, as with
+    /// [`Report::heading_color`].
+    pub fn heading_color<C: Into<Color>>(mut self, level: u8, color: C) -> Self {
+        self.heading_colors.push((level, color.into()));
+        self
+    }
+
+    /// Set the spacing between sections applied to every report, as with
+    /// [`Report::section_spacing`].
+    pub fn section_spacing<T: Into<String>>(mut self, spacing: T) -> Self {
+        self.section_spacing = Some(spacing.into());
+        self
+    }
+
+    /// Add a show rule applied to every report, as with
+    /// [`Report::add_show_rule`].
+    pub fn add_show_rule(mut self, rule: ShowRule) -> Self {
+        self.show_rules.push(rule);
+        self
+    }
+
+    /// Create a report with the given title and a copy of the template's
+    /// settings.
+    pub fn build<T: Into<String>>(&self, title: T) -> Report {
+        let mut report = Report::new(title);
+
+        if let Some(author) = &self.author {
+            report = report.author(author.clone());
+        }
+        if let Some(lang) = &self.lang {
+            report = report.lang(lang.clone());
+        }
+        if let Some(dir) = &self.text_dir {
+            report = report.text_dir(dir.clone());
+        }
+        if let Some(header) = &self.header {
+            report = report.header(header());
+        }
+        if let Some(footer) = &self.footer {
+            report = report.footer(footer());
+        }
+        if let Some(watermark) = &self.watermark {
+            report = report.watermark(watermark.clone());
+        }
+        if let Some((color, underline)) = &self.link_style {
+            report = report.link_style(color.clone(), *underline);
+        }
+        for (level, color) in &self.heading_colors {
+            report = report.heading_color(*level, color.clone());
+        }
+        if let Some(spacing) = &self.section_spacing {
+            report = report.section_spacing(spacing.clone());
+        }
+
+        self.show_rules
+            .iter()
+            .cloned()
+            .fold(report, Report::add_show_rule)
+    }
+}
+
+impl std::fmt::Debug for ReportTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReportTemplate")
+            .field("author", &self.author)
+            .field("lang", &self.lang)
+            .field("text_dir", &self.text_dir)
+            .field("header", &self.header.is_some())
+            .field("footer", &self.footer.is_some())
+            .field("watermark", &self.watermark)
+            .field("link_style", &self.link_style)
+            .field("heading_colors", &self.heading_colors)
+            .field("section_spacing", &self.section_spacing)
+            .field("show_rules", &self.show_rules)
+            .finish()
+    }
+}
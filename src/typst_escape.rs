@@ -0,0 +1,133 @@
+//! Helpers for embedding arbitrary text in generated Typst markup.
+//!
+//! Use these when building [`raw`](crate::raw) Typst by hand so user-supplied
+//! values cannot break out of the surrounding string or content block.
+
+/// Characters that carry meaning in Typst markup and must be escaped to render
+/// literally.
+///
+/// `/` starts comments and term list items, and `~` is a non-breaking space.
+const MARKUP_METACHARACTERS: [char; 13] = [
+    '\\', '#', '[', ']', '*', '_', '`', '$', '<', '>', '@', '/', '~',
+];
+
+/// Characters that start a heading, bullet list, or numbered list when they
+/// are the first thing on a line.
+const LINE_START_MARKERS: [char; 3] = ['=', '-', '+'];
+
+/// Escape a value for use inside a quoted Typst string, such as
+/// `image("...")`.
+///
+/// Backslashes and double quotes are escaped; the surrounding quotes are not
+/// added.
+///
+/// # Arguments
+/// - `value`: Text to place between double quotes.
+pub fn escape_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escape Typst markup metacharacters so the value renders as literal text,
+/// such as inside a `[...]` content block.
+///
+/// Besides the metacharacters that are special anywhere, markers that only
+/// carry meaning at the start of a line are escaped there: `=`, `-`, and `+`,
+/// and the `.` after leading digits such as `1.`. The start of the value
+/// counts as a line start, since callers may place it at one.
+///
+/// # Arguments
+/// - `value`: Plain text to place inside Typst markup.
+pub fn escape_markup(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    let mut at_line_start = true;
+
+    while let Some(ch) = chars.next() {
+        if at_line_start && ch.is_ascii_digit() {
+            escaped.push(ch);
+            while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                escaped.push(digit);
+            }
+            if chars.next_if_eq(&'.').is_some() {
+                escaped.push_str("\\.");
+            }
+            at_line_start = false;
+            continue;
+        }
+
+        if MARKUP_METACHARACTERS.contains(&ch)
+            || (at_line_start && LINE_START_MARKERS.contains(&ch))
+        {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+
+        at_line_start = match ch {
+            '\n' => true,
+            ' ' | '\t' => at_line_start,
+            _ => false,
+        };
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_string_escapes_quotes_and_backslashes() {
+        let escaped = escape_string("say \"hi\" C:\\temp");
+
+        assert_eq!(escaped, "say \\\"hi\\\" C:\\\\temp");
+    }
+
+    #[test]
+    fn escape_string_leaves_markup_metacharacters_alone() {
+        let escaped = escape_string("#[*_`$<>@]");
+
+        assert_eq!(escaped, "#[*_`$<>@]");
+    }
+
+    #[test]
+    fn escape_markup_escapes_metacharacters_and_backslashes() {
+        let escaped = escape_markup("#1 [*bold*] _x_ `c` $m$ <l> @r \\");
+
+        assert_eq!(
+            escaped,
+            "\\#1 \\[\\*bold\\*\\] \\_x\\_ \\`c\\` \\$m\\$ \\<l\\> \\@r \\\\"
+        );
+    }
+
+    #[test]
+    fn escape_markup_escapes_comments_and_non_breaking_spaces() {
+        let escaped = escape_markup("a // b /* c */ d~e");
+
+        assert_eq!(escaped, "a \\/\\/ b \\/\\* c \\*\\/ d\\~e");
+    }
+
+    #[test]
+    fn escape_markup_escapes_structure_markers_at_line_start() {
+        let escaped = escape_markup("= Pwned\n- item\n  + step\n12. entry\n/ term: x");
+
+        assert_eq!(
+            escaped,
+            "\\= Pwned\n\\- item\n  \\+ step\n12\\. entry\n\\/ term: x"
+        );
+    }
+
+    #[test]
+    fn escape_markup_leaves_markers_mid_line_alone() {
+        let escaped = escape_markup("a = b - c + d, version 1.2");
+
+        assert_eq!(escaped, "a = b - c + d, version 1.2");
+    }
+
+    #[test]
+    fn escape_markup_leaves_quotes_alone() {
+        let escaped = escape_markup("\"quoted\"");
+
+        assert_eq!(escaped, "\"quoted\"");
+    }
+}
@@ -0,0 +1,453 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::Once,
+};
+
+use comemo::Prehashed;
+use time::{OffsetDateTime, UtcOffset};
+use typst::{
+    Library, World, compile,
+    diag::{FileError, FileResult},
+    eval::Tracer,
+    foundations::{Bytes, Datetime, Smart},
+    layout::{Frame, FrameItem},
+    model::Document,
+    syntax::{FileId, Source, VirtualPath},
+    text::{Font, FontBook},
+};
+use typst_assets::fonts;
+
+use crate::report::RenderError;
+use typst_pdf::pdf;
+
+/// A Typst world backed by the embedded fonts and standard library, with
+/// imports resolved relative to the entrypoint's directory.
+pub struct ReportWorld {
+    source: Source,
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    root: PathBuf,
+    today: Option<OffsetDateTime>,
+    local_time: bool,
+    virtual_files: HashMap<FileId, Bytes>,
+}
+
+impl ReportWorld {
+    /// Create a Typst world that can compile the provided source.
+    ///
+    /// Relative entrypoint paths are resolved against the current directory and
+    /// normalized so imports resolve relative to the actual file location.
+    ///
+    /// # Arguments
+    /// - `source`: Typst source code to compile.
+    /// - `main_path`: Path to the entrypoint used for diagnostics and imports.
+    pub fn new<S: Into<String>>(source: S, main_path: &Path) -> Self {
+        let main_path = normalize_main_path(main_path);
+
+        let base_root = main_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let root = base_root.canonicalize().unwrap_or(base_root);
+
+        let main_id = FileId::new(
+            None,
+            VirtualPath::within_root(&main_path, &root)
+                .unwrap_or_else(|| VirtualPath::new(&main_path)),
+        );
+
+        let source = Source::new(main_id, source.into());
+
+        let fonts = load_embedded_fonts();
+        let book = FontBook::from_fonts(&fonts);
+
+        Self {
+            source,
+            library: Prehashed::new(Library::default()),
+            book: Prehashed::new(book),
+            fonts,
+            root,
+            today: None,
+            local_time: false,
+            virtual_files: HashMap::new(),
+        }
+    }
+
+    /// Serve `contents` for `path` without touching the disk, such as a
+    /// bibliography generated in memory.
+    ///
+    /// The path is resolved against the compile root like any other path the
+    /// document references, and takes precedence over a file on disk with
+    /// the same name.
+    pub fn with_virtual_file<P: AsRef<Path>, B: Into<Vec<u8>>>(
+        mut self,
+        path: P,
+        contents: B,
+    ) -> Self {
+        let id = FileId::new(None, VirtualPath::new(path.as_ref()));
+        self.virtual_files.insert(id, Bytes::from(contents.into()));
+        self
+    }
+
+    /// Pin the value Typst reports for `datetime.today()`.
+    ///
+    /// By default the world reads the host clock in UTC at compile time; a
+    /// fixed value makes generated documents reproducible in tests and
+    /// snapshots.
+    pub fn with_today(mut self, today: OffsetDateTime) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Configure whether `datetime.today()` without an offset uses the host's
+    /// local time zone instead of UTC. Defaults to `false`.
+    ///
+    /// UTC keeps the date independent of the machine's time zone settings.
+    /// Resolving the local offset is unreliable in multithreaded programs on
+    /// some platforms, in which case UTC is used anyway.
+    pub fn local_time(mut self, local_time: bool) -> Self {
+        self.local_time = local_time;
+        self
+    }
+
+    /// Return the font family names available to documents compiled in this
+    /// world, sorted alphabetically.
+    ///
+    /// Use these names with [`Text::font`](crate::Text::font) to pick a font
+    /// that is guaranteed to resolve.
+    pub fn available_fonts(&self) -> Vec<String> {
+        self.book
+            .families()
+            .map(|(family, _)| family.to_string())
+            .collect()
+    }
+
+    /// Compile the world's entrypoint and return the font families its text
+    /// was actually set in, sorted alphabetically.
+    ///
+    /// Unlike [`ReportWorld::available_fonts`], this only lists fonts that
+    /// ended up in the laid-out document, which helps explain why output
+    /// looks different on another machine.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::Compile`] with the compiler's error messages,
+    /// joined with `"; "`, when the document fails to compile.
+    pub fn used_fonts(&self) -> Result<Vec<String>, RenderError> {
+        self.try_compile_document()
+            .map(|document| used_font_families(&document))
+    }
+
+    /// Compile the world's entrypoint into PDF bytes.
+    ///
+    /// # Panics
+    /// Panics if the Typst document fails to compile.
+    pub fn compile_pdf(&self) -> Vec<u8> {
+        pdf(&self.compile_document(), Smart::Auto, None)
+    }
+
+    /// Compile the world's entrypoint into PDF bytes without panicking.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::Compile`] with the compiler's error messages,
+    /// joined with `"; "`, when the document fails to compile.
+    pub fn try_compile_pdf(&self) -> Result<Vec<u8>, RenderError> {
+        self.try_compile_document()
+            .map(|document| pdf(&document, Smart::Auto, None))
+    }
+
+    /// Compile the world's entrypoint into a laid-out document that can be
+    /// exported to any [`OutputFormat`](crate::OutputFormat).
+    ///
+    /// # Panics
+    /// Panics if the Typst document fails to compile.
+    pub(crate) fn compile_document(&self) -> Document {
+        self.try_compile_document()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Compile the world's entrypoint into a laid-out document.
+    ///
+    /// # Errors
+    /// Returns [`RenderError::Compile`] with the compiler's error messages,
+    /// joined with `"; "`, when the document fails to compile.
+    pub(crate) fn try_compile_document(&self) -> Result<Document, RenderError> {
+        let mut tracer = Tracer::new();
+        compile(self, &mut tracer).map_err(|diagnostics| {
+            RenderError::Compile(
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.message.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })
+    }
+
+    /// Resolve the moment `datetime.today()` should describe.
+    ///
+    /// # Arguments
+    /// - `offset`: Optional UTC offset in hours requested by the document.
+    fn now(&self, offset: Option<i64>) -> Option<OffsetDateTime> {
+        let utc_offset = match offset {
+            Some(hours) => {
+                let seconds = hours.checked_mul(3600)?;
+                Some(UtcOffset::from_whole_seconds(seconds.try_into().ok()?).ok()?)
+            }
+            None => None,
+        };
+
+        let now = match (self.today, utc_offset) {
+            (Some(today), Some(utc_offset)) => today.to_offset(utc_offset),
+            (Some(today), None) => today,
+            (None, Some(utc_offset)) => OffsetDateTime::now_utc().to_offset(utc_offset),
+            (None, None) if self.local_time => {
+                OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc())
+            }
+            (None, None) => OffsetDateTime::now_utc(),
+        };
+
+        Some(now)
+    }
+}
+
+impl World for ReportWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.source.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.source.id() {
+            return Ok(self.source.clone());
+        }
+
+        if let Some(contents) = self.virtual_files.get(&id) {
+            let text = std::str::from_utf8(contents).map_err(|_| FileError::InvalidUtf8)?;
+            return Ok(Source::new(id, text.to_string()));
+        }
+
+        let path = self.root.join(id.vpath().as_rootless_path());
+
+        let text = fs::read_to_string(&path)
+            .map_err(|_| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))?;
+
+        Ok(Source::new(id, text))
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        if let Some(contents) = self.virtual_files.get(&id) {
+            return Ok(contents.clone());
+        }
+
+        let path = self.root.join(id.vpath().as_rootless_path());
+
+        fs::read(path)
+            .map(Bytes::from)
+            .map_err(|_| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, offset: Option<i64>) -> Option<Datetime> {
+        let now = self.now(offset)?;
+
+        Datetime::from_ymd_hms(
+            now.year(),
+            now.month() as u8,
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second(),
+        )
+    }
+}
+
+/// Return the font families the text of a compiled document was set in,
+/// sorted alphabetically.
+///
+/// # Arguments
+/// - `document`: Laid-out document to inspect.
+pub(crate) fn used_font_families(document: &Document) -> Vec<String> {
+    let mut families = BTreeSet::new();
+    for page in &document.pages {
+        collect_font_families(&page.frame, &mut families);
+    }
+
+    families.into_iter().collect()
+}
+
+/// Add the family of every text run in a frame and its nested groups.
+fn collect_font_families(frame: &Frame, families: &mut BTreeSet<String>) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect_font_families(&group.frame, families),
+            FrameItem::Text(text) => {
+                families.insert(text.font.info().family.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Guards the warning about embedded fonts that failed to load so it is only
+/// printed once per process.
+static FONT_LOAD_WARNING: Once = Once::new();
+
+/// Decode the embedded font files, warning once about any that fail to load.
+fn load_embedded_fonts() -> Vec<Font> {
+    let mut loaded = Vec::new();
+    let mut failed = Vec::new();
+
+    for (index, data) in fonts().enumerate() {
+        let faces = Font::iter(Bytes::from(data.to_vec())).collect::<Vec<_>>();
+        if faces.is_empty() {
+            failed.push(index.to_string());
+        }
+        loaded.extend(faces);
+    }
+
+    if !failed.is_empty() {
+        FONT_LOAD_WARNING.call_once(|| {
+            eprintln!(
+                "warning: failed to load embedded font files at positions {}",
+                failed.join(", ")
+            );
+        });
+    }
+
+    loaded
+}
+
+/// Turn the entrypoint path into an absolute, canonical path when possible.
+///
+/// # Arguments
+/// - `main_path`: Entrypoint path as provided by the caller.
+fn normalize_main_path(main_path: &Path) -> PathBuf {
+    let main_path = if main_path.is_absolute() {
+        main_path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(main_path)
+    };
+
+    // Normalize the entrypoint path so that the Typst compiler resolves imports
+    // relative to the actual location of the input file, even when callers pass
+    // a path containing relative segments like "../".
+    main_path.canonicalize().unwrap_or(main_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{Date, Month};
+
+    fn fixed_moment(hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2024, Month::March, 15)
+            .and_then(|date| date.with_hms(hour, 30, 0))
+            .expect("fixed moment should be valid")
+            .assume_utc()
+    }
+
+    #[test]
+    fn injected_today_drives_datetime_today() {
+        let fixed = fixed_moment(9);
+        let world = ReportWorld::new(
+            "#datetime.today().display(\"[year]-[month]-[day]\")",
+            Path::new("today.typ"),
+        )
+        .with_today(fixed);
+
+        let document = world
+            .try_compile_document()
+            .expect("document should compile");
+        let text = crate::tests::text_positions(&document.pages[0].frame)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect::<String>();
+
+        assert_eq!(text, "2024-03-15");
+    }
+
+    #[test]
+    fn defaults_to_utc_date_source() {
+        let before = OffsetDateTime::now_utc().date();
+        let world = ReportWorld::new(
+            "#datetime.today().display(\"[year]-[month]-[day]\")",
+            Path::new("today.typ"),
+        );
+
+        let today = world.today(None).expect("utc date should be valid");
+        let after = OffsetDateTime::now_utc().date();
+        let pdf_bytes = world.compile_pdf();
+
+        assert!([before, after].iter().any(|date| {
+            (today.year(), today.month(), today.day())
+                == (
+                    Some(date.year()),
+                    Some(date.month() as u8),
+                    Some(date.day()),
+                )
+        }));
+        assert!(!pdf_bytes.is_empty());
+    }
+
+    #[test]
+    fn serves_virtual_files_without_disk_access() {
+        let world = ReportWorld::new(
+            "#include \"chapter.typ\"\n#read(\"notes.txt\")",
+            Path::new("virtual.typ"),
+        )
+        .with_virtual_file("chapter.typ", "= Chapter")
+        .with_virtual_file("notes.txt", "From memory");
+
+        assert!(world.try_compile_pdf().is_ok());
+    }
+
+    #[test]
+    fn lists_available_font_families() {
+        let world = ReportWorld::new("", Path::new("fonts.typ"));
+
+        let families = world.available_fonts();
+
+        assert!(!families.is_empty());
+        assert!(families.iter().any(|family| family == "DejaVu Sans Mono"));
+    }
+
+    #[test]
+    fn lists_font_families_used_by_the_document() {
+        let world = ReportWorld::new(
+            "Body text\n\n#text(font: \"DejaVu Sans Mono\")[code]",
+            Path::new("fonts.typ"),
+        );
+
+        let used = world.used_fonts().expect("document should compile");
+
+        assert!(!used.is_empty());
+        assert!(used.iter().any(|family| family == "DejaVu Sans Mono"));
+        assert!(used.len() < world.available_fonts().len());
+    }
+
+    #[test]
+    fn injected_today_honors_requested_offset() {
+        let fixed = fixed_moment(23);
+        let world = ReportWorld::new("", Path::new("today.typ")).with_today(fixed);
+
+        let shifted = world.today(Some(2)).expect("offset date should be valid");
+
+        assert_eq!(shifted.day(), Some(16));
+    }
+}
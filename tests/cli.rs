@@ -1,9 +1,9 @@
 use std::fs;
 
+use ReportCreation as reportcreation;
 use assert_cmd::Command;
 use predicates::prelude::*;
 use tempfile::tempdir;
-use ReportCreation as reportcreation;
 
 fn write_typst_fixture(dir: &std::path::Path) -> std::path::PathBuf {
     let typst_path = dir.join("sample.typ");
@@ -90,7 +90,8 @@ fn resolves_imports_relative_to_input_file() {
     let temp_dir = tempdir().expect("tempdir should be created");
 
     let template_path = temp_dir.path().join("template.typ");
-    fs::write(&template_path, "#let greeting = \"Hello from template\"\n").expect("template should be written");
+    fs::write(&template_path, "#let greeting = \"Hello from template\"\n")
+        .expect("template should be written");
 
     let nested_dir = temp_dir.path().join("reports");
     fs::create_dir_all(&nested_dir).expect("nested dir should be created");
@@ -107,5 +108,116 @@ fn resolves_imports_relative_to_input_file() {
         &input_path,
     );
 
-    assert!(!pdf_bytes.is_empty(), "pdf should be generated when import resolves");
+    assert!(
+        !pdf_bytes.is_empty(),
+        "pdf should be generated when import resolves"
+    );
+}
+
+#[test]
+fn exits_with_syntax_code_for_broken_typst() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+    let input_path = temp_dir.path().join("broken.typ");
+    fs::write(&input_path, "= Title\n#let total = (1, 2\n").expect("fixture should be written");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("broken.typ")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("error: broken.typ:2:"))
+        .stderr(predicate::str::contains("unclosed delimiter"));
+
+    assert!(fs::metadata(input_path.with_extension("pdf")).is_err());
+}
+
+#[test]
+fn exits_with_io_code_for_missing_input() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("missing.typ")
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("error: missing.typ:"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn renders_report_from_json_config() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+    let config_path = temp_dir.path().join("report.json");
+    fs::write(
+        &config_path,
+        "{\"title\": \"Config Report\", \"author\": \"CLI\"}",
+    )
+    .expect("config should be written");
+    let expected_output = temp_dir.path().join("config_report.typ");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("render")
+        .arg(&config_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config_report.typ"));
+
+    let source = fs::read_to_string(expected_output).expect("typst should be written by CLI");
+    assert!(source.contains("= Config Report"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn rejects_invalid_json_config() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+    let config_path = temp_dir.path().join("report.json");
+    fs::write(&config_path, "{\"author\": \"CLI\"}").expect("config should be written");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("render")
+        .arg(&config_path)
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "error: report config is missing a \"title\"",
+        ));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn exits_with_syntax_code_for_invalid_generated_markup() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+    let config_path = temp_dir.path().join("report.json");
+    fs::write(&config_path, "{\"title\": \"Broken \\\"quote\"}").expect("config should be written");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("render")
+        .arg(&config_path)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("error: "))
+        .stderr(predicate::str::contains("panicked").not());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn reports_compile_failures_without_panicking() {
+    let temp_dir = tempdir().expect("tempdir should be created");
+    let config_path = temp_dir.path().join("report.json");
+    fs::write(&config_path, "{\"title\": \"#missing_function()\"}")
+        .expect("config should be written");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("report_creation"))
+        .current_dir(temp_dir.path())
+        .arg("render")
+        .arg(&config_path)
+        .arg("--pdf")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("error: Typst compilation failed"))
+        .stderr(predicate::str::contains("panicked").not());
 }